@@ -0,0 +1,80 @@
+use crate::registry::Puzzle;
+use std::time::Duration;
+use util::error::Errors;
+use util::input::InputSource;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl std::ops::AddAssign for Stats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.min += rhs.min;
+        self.median += rhs.median;
+        self.max += rhs.max;
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+// Every run times parse, part 1 and part 2 together on the same input.
+fn bench(puzzle: &Puzzle, runs: usize) -> Result<Stats, Errors> {
+    let input = InputSource::Lookup {
+        year: puzzle.year,
+        day: puzzle.day,
+    }
+    .read()?;
+
+    let mut durations = (0..runs)
+        .map(|_| (puzzle.solve)(&input).map(|(_, timings)| timings.total()))
+        .collect::<Result<Vec<_>, _>>()?;
+    durations.sort();
+
+    Ok(Stats {
+        min: durations[0],
+        median: durations[runs / 2],
+        max: durations[runs - 1],
+    })
+}
+
+// Returns whether any day failed.
+pub fn run(puzzles: &[&Puzzle], runs: usize) -> bool {
+    let mut failed = false;
+
+    for year_puzzles in puzzles.chunk_by(|a, b| a.year == b.year) {
+        let year = year_puzzles[0].year;
+        let mut total = Stats::default();
+        let mut benched = 0;
+
+        for puzzle in year_puzzles {
+            match bench(puzzle, runs) {
+                Ok(stats) => {
+                    println!("{} day {:02}  {stats}", puzzle.year, puzzle.day);
+                    total += stats;
+                    benched += 1;
+                }
+                Err(error) => {
+                    eprintln!("{} day {:02} failed: {error}", puzzle.year, puzzle.day);
+                    failed = true;
+                }
+            }
+        }
+
+        if benched > 0 {
+            println!("{year} total   {total}");
+        }
+    }
+
+    failed
+}
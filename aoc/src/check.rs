@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use util::error::Errors;
+use util::input::read_answers;
+use util::solution::Answers;
+
+// The answers store is keyed by year, then zero padded day:
+//
+//     [2024.01]
+//     part1 = 11
+//     part2 = "31"
+#[derive(Clone, Debug, Default)]
+pub struct AnswerStore {
+    answers: HashMap<(u16, u8), Expected>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "fail"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+fn answer_to_string(value: &toml::Value) -> Result<String, Errors> {
+    match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        _ => Err(Errors::UncategorizedError(format!(
+            "Answers must be strings or integers, found {value}"
+        ))),
+    }
+}
+
+impl AnswerStore {
+    pub fn load() -> Result<Self, Errors> {
+        match read_answers() {
+            Ok(content) => content.parse(),
+            Err(Errors::InputNotFound(_)) => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn expected(&self, year: u16, day: u8) -> Option<&Expected> {
+        self.answers.get(&(year, day))
+    }
+}
+
+impl std::str::FromStr for AnswerStore {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = s
+            .parse::<toml::Table>()
+            .map_err(|error| Errors::UncategorizedError(format!("Invalid answers: {error}")))?;
+
+        let mut answers = HashMap::new();
+
+        for (year, days) in &table {
+            let days = days.as_table().ok_or(Errors::UncategorizedError(format!(
+                "Answers for {year} must be a table"
+            )))?;
+
+            for (day, parts) in days {
+                let parts = parts.as_table().ok_or(Errors::UncategorizedError(format!(
+                    "Answers for {year}.{day} must be a table"
+                )))?;
+
+                let expected = Expected {
+                    part1: parts.get("part1").map(answer_to_string).transpose()?,
+                    part2: parts.get("part2").map(answer_to_string).transpose()?,
+                };
+
+                answers.insert((year.parse()?, day.parse()?), expected);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+pub fn check_part(expected: Option<&String>, actual: &str) -> Status {
+    match expected {
+        Some(expected) if expected == actual => Status::Pass,
+        Some(_) => Status::Fail,
+        None => Status::Unknown,
+    }
+}
+
+pub fn check_day(expected: Option<&Expected>, answers: &Answers) -> [Status; 2] {
+    let expected = expected.cloned().unwrap_or_default();
+
+    [
+        check_part(expected.part1.as_ref(), &answers.part1),
+        match &answers.part2 {
+            Some(part2) => check_part(expected.part2.as_ref(), part2),
+            None => Status::Unknown,
+        },
+    ]
+}
+
+// A day passes when every known answer matches and at least one answer is known.
+pub fn day_status(statuses: &[Status]) -> Status {
+    if statuses.contains(&Status::Fail) {
+        Status::Fail
+    } else if statuses.contains(&Status::Pass) {
+        Status::Pass
+    } else {
+        Status::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: &str, part2: Option<&str>) -> Answers {
+        Answers {
+            part1: part1.into(),
+            part2: part2.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn parses_integer_and_string_answers() {
+        let store = "[2024.01]\npart1 = 11\npart2 = \"31\"\n\n[2015.04]\npart1 = \"abc\"\n"
+            .parse::<AnswerStore>()
+            .unwrap();
+
+        let day = store.expected(2024, 1).unwrap();
+        assert_eq!(day.part1.as_deref(), Some("11"));
+        assert_eq!(day.part2.as_deref(), Some("31"));
+
+        let day = store.expected(2015, 4).unwrap();
+        assert_eq!(day.part1.as_deref(), Some("abc"));
+        assert_eq!(day.part2, None);
+        assert!(store.expected(2024, 2).is_none());
+    }
+
+    #[test]
+    fn rejects_bad_tables() {
+        for bad in [
+            "[2024.01\npart1 = 1",
+            "2024 = 5",
+            "[2024]\n01 = 5",
+            "[2024.01]\npart1 = 1.5",
+            "[2024.xx]\npart1 = 1",
+            "[twenty.01]\npart1 = 1",
+        ] {
+            assert!(bad.parse::<AnswerStore>().is_err(), "{bad:?}");
+        }
+        assert!("".parse::<AnswerStore>().is_ok());
+    }
+
+    #[test]
+    fn checks_each_part() {
+        let expected = Expected {
+            part1: Some("11".into()),
+            part2: None,
+        };
+
+        assert_eq!(
+            check_day(Some(&expected), &answers("11", Some("31"))),
+            [Status::Pass, Status::Unknown]
+        );
+        assert_eq!(
+            check_day(Some(&expected), &answers("12", None)),
+            [Status::Fail, Status::Unknown]
+        );
+        assert_eq!(
+            check_day(None, &answers("11", Some("31"))),
+            [Status::Unknown, Status::Unknown]
+        );
+    }
+
+    #[test]
+    fn day_status_needs_a_known_answer() {
+        use Status::*;
+
+        assert_eq!(day_status(&[Pass, Unknown]), Pass);
+        assert_eq!(day_status(&[Pass, Fail]), Fail);
+        assert_eq!(day_status(&[Unknown, Fail]), Fail);
+        assert_eq!(day_status(&[Unknown, Unknown]), Unknown);
+    }
+}
@@ -0,0 +1,166 @@
+mod bench;
+mod check;
+mod options;
+mod pool;
+mod registry;
+mod report;
+mod scaffold;
+
+use check::{AnswerStore, Status, check_day};
+use options::{Format, Mode, Options, Selection, USAGE};
+use registry::{PUZZLES, Puzzle};
+use report::{DayReport, print_failure};
+use std::process::ExitCode;
+use util::error::Errors;
+use util::input::InputSource;
+use util::inputs::InputCache;
+
+// Inputs missing locally are only downloaded with `--fetch`, into the per-user cache.
+fn read_input(source: &InputSource, fetch: bool) -> Result<String, Errors> {
+    match (source.read(), source) {
+        (Err(Errors::InputNotFound(tried)), &InputSource::Lookup { year, day }) if fetch => {
+            match InputCache::from_env() {
+                Some(cache) => cache.get(year, day),
+                None => Err(Errors::InputNotFound(tried)),
+            }
+        }
+        (result, _) => result,
+    }
+}
+
+fn run(
+    puzzle: &Puzzle,
+    source: &InputSource,
+    fetch: bool,
+    store: Option<&AnswerStore>,
+) -> Result<DayReport, Errors> {
+    let input = read_input(source, fetch)?;
+
+    let (answers, timings) = (puzzle.solve)(&input)?;
+
+    let expected = store.and_then(|store| store.expected(puzzle.year, puzzle.day));
+    let statuses = store.map(|_| check_day(expected, &answers));
+
+    Ok(DayReport {
+        year: puzzle.year,
+        day: puzzle.day,
+        answers,
+        timings,
+        expected: expected.cloned(),
+        statuses,
+    })
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let options = match Options::from_args(&args) {
+        Ok(options) => options,
+        Err(_) => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if let (Mode::New, Selection::Day(year, day)) = (options.mode, options.selection) {
+        return match scaffold::new_day(year, day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("Could not create {year} day {day:02}: {error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let puzzles = PUZZLES
+        .iter()
+        .filter(|puzzle| options.selection.matches(puzzle))
+        .collect::<Vec<_>>();
+
+    if puzzles.is_empty() {
+        eprintln!("No puzzle registered for {:?}", options.selection);
+        return ExitCode::FAILURE;
+    }
+
+    if let Mode::Bench { runs } = options.mode {
+        return if bench::run(&puzzles, runs) {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    let store = if options.check {
+        match AnswerStore::load() {
+            Ok(store) => Some(store),
+            Err(error) => {
+                eprintln!("Could not load answers: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut failed = false;
+    let mut summary = [0usize; 3];
+    let mut errored = 0usize;
+
+    let work = |puzzle: &&Puzzle| {
+        let source = options.source.clone().unwrap_or(InputSource::Lookup {
+            year: puzzle.year,
+            day: puzzle.day,
+        });
+
+        run(puzzle, &source, options.fetch, store.as_ref())
+    };
+
+    let emit = |puzzle: &&Puzzle, outcome: Result<Result<DayReport, Errors>, String>| {
+        // A day that panicked fails like any other.
+        let result = outcome.unwrap_or_else(|message| {
+            Err(Errors::UncategorizedError(format!("panicked: {message}")))
+        });
+
+        match result {
+            Ok(report) => {
+                match options.format {
+                    Format::Text => report.print_text(options.time),
+                    Format::Json => report.print_json(),
+                }
+
+                if let Some(status) = report.status() {
+                    summary[status as usize] += 1;
+                    failed |= status == Status::Fail;
+                }
+            }
+            Err(error) => {
+                print_failure(puzzle, &error, options.format == Format::Json);
+                errored += 1;
+                failed = true;
+            }
+        }
+    };
+
+    pool::execute(&puzzles, options.jobs, |puzzle| puzzle.heavy, work, emit);
+
+    if options.check {
+        let line = format!(
+            "check: {} passed, {} failed, {} unknown, {errored} errored",
+            summary[Status::Pass as usize],
+            summary[Status::Fail as usize],
+            summary[Status::Unknown as usize]
+        );
+
+        // Keep stdout parseable as one record per line.
+        match options.format {
+            Format::Text => println!("{line}"),
+            Format::Json => eprintln!("{line}"),
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
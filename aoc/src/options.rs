@@ -0,0 +1,152 @@
+use crate::pool::default_jobs;
+use crate::registry::Puzzle;
+use util::error::Errors;
+use util::input::InputSource;
+
+pub const USAGE: &str = "\
+usage: aoc [--check] [--time] [--fetch] [--format text|json] [--jobs <n>] all | <year> [<day> [<input file> | -]]
+       aoc bench [--runs <n>] all | <year> [<day>]
+       aoc new <year> <day>";
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Selection {
+    fn from_args(args: &[&str]) -> Result<(Self, Option<InputSource>), Errors> {
+        match *args {
+            ["all"] => Ok((Selection::All, None)),
+            [year] => Ok((Selection::Year(year.parse()?), None)),
+            [year, day] => Ok((Selection::Day(year.parse()?, day.parse()?), None)),
+            [year, day, input] => Ok((
+                Selection::Day(year.parse()?, day.parse()?),
+                Some(InputSource::from_arg(input)),
+            )),
+            _ => Err(Errors::UncategorizedError(USAGE.into())),
+        }
+    }
+
+    pub fn matches(self, puzzle: &Puzzle) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Year(year) => puzzle.year == year,
+            Selection::Day(year, day) => puzzle.year == year && puzzle.day == day,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    Run,
+    Bench { runs: usize },
+    New,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub mode: Mode,
+    pub selection: Selection,
+    pub source: Option<InputSource>,
+    pub check: bool,
+    pub time: bool,
+    pub fetch: bool,
+    pub format: Format,
+    pub jobs: usize,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Result<Self, Errors> {
+        let usage = || Errors::UncategorizedError(USAGE.into());
+
+        let mut args = args.iter().map(String::as_str).peekable();
+
+        if args.next_if_eq(&"new").is_some() {
+            let (selection, source) = Selection::from_args(&args.collect::<Vec<_>>())?;
+            if !matches!(selection, Selection::Day(..)) || source.is_some() {
+                return Err(usage());
+            }
+
+            return Ok(Self {
+                mode: Mode::New,
+                selection,
+                source,
+                check: false,
+                time: false,
+                fetch: false,
+                format: Format::Text,
+                jobs: 1,
+            });
+        }
+
+        let bench = args.next_if_eq(&"bench").is_some();
+        let mut runs = DEFAULT_BENCH_RUNS;
+        let mut check = false;
+        let mut time = false;
+        let mut fetch = false;
+        let mut format = Format::Text;
+        let mut jobs = default_jobs();
+        let mut positional = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--check" if !bench => check = true,
+                "--time" if !bench => time = true,
+                "--fetch" if !bench => fetch = true,
+                "--format" if !bench => {
+                    format = match args.next() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        _ => return Err(usage()),
+                    };
+                }
+                "--jobs" if !bench => {
+                    jobs = args.next().ok_or_else(usage)?.parse()?;
+                    if jobs == 0 {
+                        return Err(usage());
+                    }
+                }
+                "--runs" if bench => {
+                    runs = args.next().ok_or_else(usage)?.parse()?;
+                    if runs == 0 {
+                        return Err(usage());
+                    }
+                }
+                flag if flag.starts_with("--") => return Err(usage()),
+                arg => positional.push(arg),
+            }
+        }
+
+        let (selection, source) = Selection::from_args(&positional)?;
+
+        let mode = if bench {
+            if source.is_some() {
+                return Err(usage());
+            }
+            Mode::Bench { runs }
+        } else {
+            Mode::Run
+        };
+
+        Ok(Self {
+            mode,
+            selection,
+            source,
+            check,
+            time,
+            fetch,
+            format,
+            jobs,
+        })
+    }
+}
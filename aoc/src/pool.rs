@@ -0,0 +1,168 @@
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or_else(
+            || "unknown panic".to_owned(),
+            |message| (*message).to_owned(),
+        ),
+    }
+}
+
+// Runs `work` for every item and hands the results to `emit` in the order of `items`, as soon
+// as all earlier items are done. No more than `jobs` threads run at once. When there are
+// threads to spare, pinned items get one of their own, so a few heavy items cannot occupy the
+// workers shared by all the others; otherwise they are queued first. An item whose `work`
+// panics is emitted with the panic message, and the remaining items still run.
+pub fn execute<T, R, W, E>(
+    items: &[T],
+    jobs: usize,
+    pinned: impl Fn(&T) -> bool,
+    work: W,
+    mut emit: E,
+) where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    E: FnMut(&T, Result<R, String>),
+{
+    let jobs = jobs.max(1);
+    let (mut pinned, mut shared): (Vec<_>, Vec<_>) =
+        (0..items.len()).partition(|&i| pinned(&items[i]));
+    if pinned.len() >= jobs {
+        shared = std::mem::take(&mut pinned)
+            .into_iter()
+            .chain(shared)
+            .collect();
+    }
+    let workers = (jobs - pinned.len()).min(shared.len()).max(1);
+    let next = AtomicUsize::new(0);
+
+    let work = &|item: &T| catch_unwind(AssertUnwindSafe(|| work(item))).map_err(panic_message);
+    let shared = &shared;
+    let next = &next;
+
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+
+        for idx in pinned {
+            let sender = sender.clone();
+            scope.spawn(move || sender.send((idx, work(&items[idx]))));
+        }
+
+        for _ in 0..workers {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                while let Some(&idx) = shared.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send((idx, work(&items[idx]))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut results = items.iter().map(|_| None).collect::<Vec<_>>();
+        let mut emitted = 0;
+
+        for (idx, result) in receiver {
+            results[idx] = Some(result);
+
+            while let Some(result) = results.get_mut(emitted).and_then(Option::take) {
+                emit(&items[emitted], result);
+                emitted += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn emits_in_order_of_items() {
+        // Earlier items sleep longer, so they finish last.
+        let items = (0..8u64).collect::<Vec<_>>();
+        let mut emitted = Vec::new();
+
+        execute(
+            &items,
+            2,
+            |&item| item % 3 == 0,
+            |&item| {
+                std::thread::sleep(Duration::from_millis(5 * (8 - item)));
+                item * 10
+            },
+            |&item, result| emitted.push((item, result.unwrap())),
+        );
+
+        assert_eq!(
+            emitted,
+            items.iter().map(|&i| (i, i * 10)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn threads_stay_within_jobs() {
+        let items = (0..12).collect::<Vec<_>>();
+
+        for (jobs, pinned) in [(1, 4), (2, 1), (3, 2), (3, 6), (4, 0)] {
+            let (active, most) = (AtomicUsize::new(0), AtomicUsize::new(0));
+            let mut threads = Vec::new();
+
+            execute(
+                &items,
+                jobs,
+                |&item| item < pinned,
+                |_| {
+                    most.fetch_max(active.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(2));
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    std::thread::current().id()
+                },
+                |_, result| threads.push(result.unwrap()),
+            );
+
+            assert_eq!(threads.len(), items.len());
+            assert!(most.into_inner() <= jobs, "jobs {jobs}, pinned {pinned}");
+            if jobs == 1 {
+                assert!(threads.iter().all(|&id| id == threads[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn panics_fail_only_their_item() {
+        let items = (0..6).collect::<Vec<_>>();
+        let mut emitted = Vec::new();
+
+        execute(
+            &items,
+            1,
+            |&item| item == 4,
+            |&item| {
+                if item == 1 || item == 4 {
+                    panic!("item {item} broke");
+                }
+                item
+            },
+            |&item, result| emitted.push((item, result)),
+        );
+
+        assert_eq!(emitted.len(), items.len());
+        assert_eq!(emitted[1].1, Err("item 1 broke".to_owned()));
+        assert_eq!(emitted[4].1, Err("item 4 broke".to_owned()));
+        assert_eq!(emitted[5], (5, Ok(5)));
+    }
+}
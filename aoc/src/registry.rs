@@ -0,0 +1,106 @@
+use util::error::Errors;
+use util::solution::{Answers, Timings, solve};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str) -> Result<(Answers, Timings), Errors>,
+    // Heavy days run on a thread of their own instead of taking a shared worker.
+    pub heavy: bool,
+}
+
+macro_rules! puzzle {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Puzzle {
+            year: $year,
+            day: $day,
+            solve: solve::<$krate::Day>,
+            heavy: false,
+        }
+    };
+    ($year:literal, $day:literal, $krate:ident, heavy) => {
+        Puzzle {
+            year: $year,
+            day: $day,
+            solve: solve::<$krate::Day>,
+            heavy: true,
+        }
+    };
+}
+
+// z3 needs a system library and libclang to build, so its day can be left out.
+#[cfg(not(feature = "z3"))]
+mod year2025day10 {
+    use util::error::Errors;
+    use util::solution::Solution;
+
+    fn unavailable() -> Errors {
+        Errors::UncategorizedError("aoc was built without the z3 feature".into())
+    }
+
+    pub struct Day;
+
+    impl Solution for Day {
+        type Input = ();
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(_input: &str) -> Result<Self::Input, Errors> {
+            Err(unavailable())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<usize, Errors> {
+            Err(unavailable())
+        }
+
+        fn part2(_input: &Self::Input) -> Result<usize, Errors> {
+            Err(unavailable())
+        }
+    }
+}
+
+// Days are listed once they are solved, so that runs over a whole year pass.
+pub static PUZZLES: &[Puzzle] = &[
+    puzzle!(2015, 1, year2015day01),
+    puzzle!(2015, 2, year2015day02),
+    puzzle!(2015, 3, year2015day03),
+    puzzle!(2015, 4, year2015day04, heavy),
+    puzzle!(2024, 1, year2024day01),
+    puzzle!(2024, 2, year2024day02),
+    puzzle!(2024, 3, year2024day03),
+    puzzle!(2024, 4, year2024day04),
+    puzzle!(2024, 5, year2024day05),
+    puzzle!(2024, 6, year2024day06),
+    puzzle!(2024, 7, year2024day07),
+    puzzle!(2024, 8, year2024day08),
+    puzzle!(2024, 9, year2024day09),
+    puzzle!(2024, 10, year2024day10),
+    puzzle!(2024, 11, year2024day11),
+    puzzle!(2024, 12, year2024day12),
+    puzzle!(2024, 13, year2024day13),
+    puzzle!(2024, 14, year2024day14),
+    puzzle!(2024, 15, year2024day15),
+    puzzle!(2024, 16, year2024day16),
+    puzzle!(2024, 17, year2024day17),
+    puzzle!(2024, 18, year2024day18),
+    puzzle!(2024, 19, year2024day19),
+    puzzle!(2024, 20, year2024day20),
+    puzzle!(2024, 21, year2024day21),
+    puzzle!(2024, 22, year2024day22),
+    puzzle!(2024, 23, year2024day23),
+    puzzle!(2024, 24, year2024day24),
+    puzzle!(2024, 25, year2024day25),
+    puzzle!(2025, 1, year2025day01),
+    puzzle!(2025, 2, year2025day02),
+    puzzle!(2025, 3, year2025day03),
+    puzzle!(2025, 4, year2025day04),
+    puzzle!(2025, 5, year2025day05),
+    puzzle!(2025, 6, year2025day06),
+    puzzle!(2025, 7, year2025day07),
+    puzzle!(2025, 8, year2025day08),
+    puzzle!(2025, 9, year2025day09),
+    puzzle!(2025, 10, year2025day10, heavy),
+    puzzle!(2025, 11, year2025day11),
+    puzzle!(2025, 12, year2025day12),
+];
@@ -0,0 +1,180 @@
+use crate::check::{Expected, Status, day_status};
+use crate::registry::Puzzle;
+use std::time::Duration;
+use util::error::Errors;
+use util::solution::{Answers, Timings};
+
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub answers: Answers,
+    pub timings: Timings,
+    pub expected: Option<Expected>,
+    pub statuses: Option<[Status; 2]>,
+}
+
+fn with_status(answer: &str, status: Option<Status>, expected: Option<&String>) -> String {
+    match (status, expected) {
+        (Some(Status::Fail), Some(expected)) => format!("{answer} (fail, expected {expected})"),
+        (Some(status), _) => format!("{answer} ({status})"),
+        (None, _) => answer.to_owned(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+
+    result
+}
+
+fn json_record(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    answer: Option<&str>,
+    duration: Option<Duration>,
+    status: &str,
+) -> String {
+    let null = || "null".to_owned();
+
+    format!(
+        "{{\"year\":{year},\"day\":{day},\"part\":{},\"answer\":{},\"duration_ns\":{},\"status\":{}}}",
+        part.map_or_else(null, |part| part.to_string()),
+        answer.map_or_else(null, json_string),
+        duration.map_or_else(null, |duration| duration.as_nanos().to_string()),
+        json_string(status)
+    )
+}
+
+impl DayReport {
+    pub fn status(&self) -> Option<Status> {
+        self.statuses.map(|statuses| day_status(&statuses))
+    }
+
+    pub fn print_text(&self, time: bool) {
+        let expected = self.expected.as_ref();
+
+        println!("{} day {:02}", self.year, self.day);
+        println!(
+            "  part 1: {}",
+            with_status(
+                &self.answers.part1,
+                self.statuses.map(|s| s[0]),
+                expected.and_then(|e| e.part1.as_ref())
+            )
+        );
+        if let Some(part2) = &self.answers.part2 {
+            println!(
+                "  part 2: {}",
+                with_status(
+                    part2,
+                    self.statuses.map(|s| s[1]),
+                    expected.and_then(|e| e.part2.as_ref())
+                )
+            );
+        }
+
+        if time {
+            print!(
+                "  time: parse {:.2?}, part 1 {:.2?}",
+                self.timings.parse, self.timings.part1
+            );
+            if let Some(part2) = self.timings.part2 {
+                print!(", part 2 {part2:.2?}");
+            }
+            println!();
+        }
+    }
+
+    // One record per part, without a check every answer is reported as "ok".
+    pub fn print_json(&self) {
+        let status = |part: usize| match self.statuses {
+            Some(statuses) => statuses[part].to_string(),
+            None => "ok".to_owned(),
+        };
+
+        println!(
+            "{}",
+            json_record(
+                self.year,
+                self.day,
+                Some(1),
+                Some(&self.answers.part1),
+                Some(self.timings.part1),
+                &status(0)
+            )
+        );
+        if let Some(part2) = &self.answers.part2 {
+            println!(
+                "{}",
+                json_record(
+                    self.year,
+                    self.day,
+                    Some(2),
+                    Some(part2),
+                    self.timings.part2,
+                    &status(1)
+                )
+            );
+        }
+    }
+}
+
+pub fn print_failure(puzzle: &Puzzle, error: &Errors, json: bool) {
+    eprintln!("{} day {:02} failed: {error}", puzzle.year, puzzle.day);
+
+    if json {
+        println!(
+            "{}",
+            json_record(puzzle.year, puzzle.day, None, None, None, "error")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("a\\b"), "\"a\\\\b\"");
+        assert_eq!(json_string("1\n2\r\t3"), "\"1\\n2\\r\\t3\"");
+        assert_eq!(json_string("\u{1}\u{7f}é"), "\"\\u0001\\u007fé\"");
+    }
+
+    #[test]
+    fn json_records() {
+        assert_eq!(
+            json_record(
+                2024,
+                1,
+                Some(2),
+                Some("a\"b"),
+                Some(Duration::from_micros(3)),
+                "pass"
+            ),
+            r#"{"year":2024,"day":1,"part":2,"answer":"a\"b","duration_ns":3000,"status":"pass"}"#
+        );
+        assert_eq!(
+            json_record(2015, 5, None, None, None, "error"),
+            r#"{"year":2015,"day":5,"part":null,"answer":null,"duration_ns":null,"status":"error"}"#
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+use util::error::Errors;
+use util::input::answers_candidates;
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+util = { workspace = true }
+"#;
+
+const LIB_TEMPLATE: &str = r#"use util::error::Errors;
+use util::solution::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(input.trim_end().split('\n').map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<usize, Errors> {
+        Err(Errors::UncategorizedError("Not solved yet".into()))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize, Errors> {
+        Err(Errors::UncategorizedError("Not solved yet".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+";
+
+    util::examples! {
+        Day,
+        #[ignore = "example not filled in yet"]
+        example: EXAMPLE => part1 = 0, part2 = 0;
+    }
+}
+"#;
+
+fn workspace_root() -> Result<PathBuf, Errors> {
+    let cwd = std::env::current_dir()?;
+
+    for dir in cwd.ancestors() {
+        let manifest = dir.join("Cargo.toml");
+        if manifest.is_file() && std::fs::read_to_string(&manifest)?.contains("[workspace]") {
+            return Ok(dir.to_path_buf());
+        }
+    }
+
+    Err(Errors::UncategorizedError(
+        "Not inside the aoc workspace".into(),
+    ))
+}
+
+// Inserts `line` in front of the first line whose key sorts after it, keeping lists like the
+// workspace members ordered by year and day. Lines without a key are left alone.
+fn insert_sorted<K: Ord>(
+    content: &str,
+    line: &str,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<String, Errors> {
+    let new_key = key(line).ok_or(Errors::ParseError)?;
+    let mut lines = content.lines().collect::<Vec<_>>();
+
+    let mut position = None;
+    for (idx, existing) in lines.iter().enumerate() {
+        match key(existing) {
+            Some(existing_key) if existing_key == new_key => {
+                return Err(Errors::UncategorizedError(format!(
+                    "{} is already registered",
+                    line.trim()
+                )));
+            }
+            Some(existing_key) if existing_key > new_key => {
+                position = Some(idx);
+                break;
+            }
+            Some(_) => position = Some(idx + 1),
+            None => {}
+        }
+    }
+
+    let position = position.ok_or(Errors::UncategorizedError(format!(
+        "Nowhere to insert {}",
+        line.trim()
+    )))?;
+    lines.insert(position, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Keys `year2024/day01` and `year2024day01` lines by (year, day).
+fn crate_key(line: &str) -> Option<(u16, u8)> {
+    let line = line.trim().trim_start_matches('"');
+    let rest = line.strip_prefix("year")?;
+    let year = rest.get(..4)?.parse().ok()?;
+    let rest = rest[4..].trim_start_matches('/').strip_prefix("day")?;
+    let day = rest.get(..2)?.parse().ok()?;
+
+    Some((year, day))
+}
+
+fn add_answer_placeholder(year: u16, day: u8) -> Result<PathBuf, Errors> {
+    let path = answers_candidates().swap_remove(0);
+    let header = format!("[{year}.{day:02}]");
+
+    let mut content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+
+    if !content.lines().any(|line| line.trim() == header) {
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        content.push_str(&format!("{header}\n# part1 = \n# part2 = \n"));
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+    }
+
+    Ok(path)
+}
+
+pub fn new_day(year: u16, day: u8) -> Result<(), Errors> {
+    if !(1..=25).contains(&day) {
+        return Err(Errors::UncategorizedError(format!("Invalid day {day}")));
+    }
+
+    let root = workspace_root()?;
+    let krate = format!("year{year}day{day:02}");
+    let member = format!("year{year}/day{day:02}");
+    let dir = root.join(&member);
+
+    if dir.exists() {
+        return Err(Errors::UncategorizedError(format!(
+            "{} already exists",
+            dir.display()
+        )));
+    }
+
+    // Everything is prepared before the first write, so a failure leaves the tree untouched.
+    let manifest = std::fs::read_to_string(root.join("Cargo.toml"))?;
+    let manifest = insert_sorted(&manifest, &format!("    \"{member}\","), crate_key)?;
+    let runner_manifest = std::fs::read_to_string(root.join("aoc/Cargo.toml"))?;
+    let runner_manifest = insert_sorted(
+        &runner_manifest,
+        &format!("{krate} = {{ path = \"../{member}\" }}"),
+        crate_key,
+    )?;
+
+    std::fs::create_dir_all(dir.join("src"))?;
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        CARGO_TEMPLATE.replace("{crate}", &krate),
+    )?;
+    std::fs::write(dir.join("src/lib.rs"), LIB_TEMPLATE)?;
+
+    std::fs::write(root.join("Cargo.toml"), manifest)?;
+    std::fs::write(root.join("aoc/Cargo.toml"), runner_manifest)?;
+
+    let answers = add_answer_placeholder(year, day)?;
+
+    // The day stays out of `PUZZLES` until it is solved, see the registry.
+    println!("Created {member} and added it to the workspace and runner dependencies");
+    println!("Answer placeholder added to {}", answers.display());
+    println!("Once it is solved, add `puzzle!({year}, {day}, {krate}),` to aoc/src/registry.rs");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = "[workspace]
+members = [
+    \"aoc\",
+    \"year2015/day01\",
+    \"year2024/day01\",
+    \"year2024/day03\",
+]
+";
+
+    #[test]
+    fn keys_by_year_and_day() {
+        assert_eq!(crate_key("    \"year2024/day07\","), Some((2024, 7)));
+        assert_eq!(
+            crate_key("year2025day12 = { path = \"../year2025/day12\" }"),
+            Some((2025, 12))
+        );
+        assert_eq!(crate_key("    \"aoc\","), None);
+        assert_eq!(crate_key("util = { workspace = true }"), None);
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let middle = insert_sorted(MEMBERS, "    \"year2024/day02\",", crate_key).unwrap();
+        assert!(middle.contains("day01\",\n    \"year2024/day02\",\n    \"year2024/day03"));
+
+        let first = insert_sorted(MEMBERS, "    \"year2014/day25\",", crate_key).unwrap();
+        assert!(first.contains("\"aoc\",\n    \"year2014/day25\",\n    \"year2015/day01"));
+
+        let last = insert_sorted(MEMBERS, "    \"year2025/day01\",", crate_key).unwrap();
+        assert!(last.ends_with("\"year2024/day03\",\n    \"year2025/day01\",\n]\n"));
+    }
+
+    #[test]
+    fn rejects_duplicates_and_unkeyed_lines() {
+        assert!(matches!(
+            insert_sorted(MEMBERS, "    \"year2024/day03\",", crate_key),
+            Err(Errors::UncategorizedError(_))
+        ));
+        assert!(insert_sorted(MEMBERS, "    \"util\",", crate_key).is_err());
+        assert!(insert_sorted("[workspace]\n", "    \"year2024/day01\",", crate_key).is_err());
+    }
+}
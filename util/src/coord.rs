@@ -0,0 +1,516 @@
+use crate::error::Errors;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Coord<T, const N: usize>(pub [T; N]);
+
+pub type UCoord2D = Coord<usize, 2>;
+pub type ICoord2D = Coord<isize, 2>;
+pub type UCoord3D = Coord<usize, 3>;
+pub type ICoord3D = Coord<isize, 3>;
+pub type UCoord4D = Coord<usize, 4>;
+pub type ICoord4D = Coord<isize, 4>;
+
+// Named accessors for the components, so `coord.x()` reads the first one and
+// `*coord.x_mut() += 1` moves along it.
+macro_rules! named_components {
+    ($n:literal { $($idx:literal => $get:ident, $get_mut:ident);+ }) => {
+        impl<T> Coord<T, $n> {
+            #[inline(always)]
+            pub const fn new($($get: T),+) -> Self {
+                Coord([$($get),+])
+            }
+
+            $(
+                #[inline(always)]
+                pub fn $get_mut(&mut self) -> &mut T {
+                    &mut self.0[$idx]
+                }
+            )+
+        }
+
+        impl<T: Copy> Coord<T, $n> {
+            $(
+                #[inline(always)]
+                pub const fn $get(self) -> T {
+                    self.0[$idx]
+                }
+            )+
+        }
+    };
+}
+
+named_components!(2 { 0 => x, x_mut; 1 => y, y_mut });
+named_components!(3 { 0 => x, x_mut; 1 => y, y_mut; 2 => z, z_mut });
+named_components!(4 { 0 => x, x_mut; 1 => y, y_mut; 2 => z, z_mut; 3 => w, w_mut });
+
+impl<T: Default, const N: usize> Default for Coord<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Coord(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Copy, const N: usize> Coord<T, N> {
+    #[inline(always)]
+    fn zip_with<U: Copy, V>(self, rhs: Coord<U, N>, mut f: impl FnMut(T, U) -> V) -> Coord<V, N> {
+        Coord(std::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+
+    #[inline(always)]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Coord<U, N> {
+        Coord(self.0.map(f))
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Coord<T, N> {
+    #[inline(always)]
+    pub fn component_min(self, rhs: Self) -> Self {
+        self.zip_with(rhs, Ord::min)
+    }
+
+    #[inline(always)]
+    pub fn component_max(self, rhs: Self) -> Self {
+        self.zip_with(rhs, Ord::max)
+    }
+}
+
+impl<const N: usize> Coord<isize, N> {
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        self.map(isize::abs)
+    }
+
+    #[inline(always)]
+    pub fn signum(self) -> Self {
+        self.map(isize::signum)
+    }
+
+    // Wraps every component into `0..dim`, treating the area as a torus.
+    #[inline(always)]
+    pub fn rem_euclid(self, dim: Self) -> Self {
+        self.zip_with(dim, isize::rem_euclid)
+    }
+
+    // Moves by `offset` on a torus of `dim`, wrapping around the edges.
+    #[inline(always)]
+    pub fn wrapping_add(self, offset: Self, dim: Self) -> Self {
+        (self + offset).rem_euclid(dim)
+    }
+
+    #[inline(always)]
+    pub fn magnitude(&self) -> f64 {
+        self.0
+            .iter()
+            .map(|&v| v as f64 * v as f64)
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl<T: FromStr, const N: usize> Coord<T, N>
+where
+    Errors: From<T::Err>,
+{
+    // Parses exactly `N` components separated by `separator`, ignoring surrounding whitespace.
+    pub fn parse_with_separator(s: &str, separator: &str) -> Result<Self, Errors> {
+        let mut parts = s.trim().split(separator);
+        let mut components = Vec::with_capacity(N);
+        for part in parts.by_ref().take(N) {
+            components.push(part.trim().parse::<T>()?);
+        }
+        if parts.next().is_some() {
+            return Err(Errors::ParseError);
+        }
+
+        Ok(Coord(
+            components.try_into().map_err(|_| Errors::ParseError)?,
+        ))
+    }
+}
+
+impl<T: FromStr, const N: usize> FromStr for Coord<T, N>
+where
+    Errors: From<T::Err>,
+{
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_separator(s, ",")
+    }
+}
+
+impl<T: Display, const N: usize> Coord<T, N> {
+    // The components joined by `separator`, e.g. `x y` instead of the default `x,y`.
+    pub fn display_with<'a>(&'a self, separator: &'a str) -> impl Display + 'a {
+        Separated(self, separator)
+    }
+}
+
+struct Separated<'a, T, const N: usize>(&'a Coord<T, N>, &'a str);
+
+impl<T: Display, const N: usize> Display for Separated<'_, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, component) in self.0.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.1)?;
+            }
+            write!(f, "{component}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display, const N: usize> Display for Coord<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_with(","))
+    }
+}
+
+macro_rules! distances {
+    ($t:ty) => {
+        impl<const N: usize> Coord<$t, N> {
+            #[inline(always)]
+            pub fn manhattan(self, other: Self) -> usize {
+                self.0.iter().zip(other.0).map(|(a, b)| a.abs_diff(b)).sum()
+            }
+
+            #[inline(always)]
+            pub fn chebyshev(self, other: Self) -> usize {
+                self.0
+                    .iter()
+                    .zip(other.0)
+                    .map(|(a, b)| a.abs_diff(b))
+                    .max()
+                    .unwrap_or(0)
+            }
+
+            // The squared euclidean distance, which is exact and orders like the distance.
+            #[inline(always)]
+            pub fn euclidean_sq(self, other: Self) -> usize {
+                self.0
+                    .iter()
+                    .zip(other.0)
+                    .map(|(a, b)| a.abs_diff(b).pow(2))
+                    .sum()
+            }
+        }
+    };
+}
+
+distances!(usize);
+distances!(isize);
+
+impl<const N: usize> Coord<isize, N> {
+    // All points at most `radius` steps away, counting steps along the axes.
+    pub fn within_manhattan(self, radius: usize) -> impl Iterator<Item = Self> {
+        Ball::new(self, radius, Metric::Manhattan)
+    }
+
+    // All points at most `radius` steps away, diagonal steps included, i.e. the cube around
+    // `self`.
+    pub fn within_chebyshev(self, radius: usize) -> impl Iterator<Item = Self> {
+        Ball::new(self, radius, Metric::Chebyshev)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Metric {
+    Manhattan,
+    Chebyshev,
+}
+
+// Walks the offsets like an odometer in lexicographic order, where each component ranges
+// over what the radius leaves after the components before it.
+struct Ball<const N: usize> {
+    center: Coord<isize, N>,
+    radius: isize,
+    metric: Metric,
+    offset: Option<[isize; N]>,
+}
+
+impl<const N: usize> Ball<N> {
+    fn new(center: Coord<isize, N>, radius: usize, metric: Metric) -> Self {
+        let mut ball = Self {
+            center,
+            radius: radius as isize,
+            metric,
+            offset: None,
+        };
+        let mut offset = [0; N];
+        ball.reset_from(&mut offset, 0);
+        ball.offset = Some(offset);
+        ball
+    }
+
+    fn limit(&self, offset: &[isize; N], i: usize) -> isize {
+        match self.metric {
+            Metric::Manhattan => self.radius - offset[..i].iter().map(|v| v.abs()).sum::<isize>(),
+            Metric::Chebyshev => self.radius,
+        }
+    }
+
+    fn reset_from(&self, offset: &mut [isize; N], from: usize) {
+        for i in from..N {
+            offset[i] = -self.limit(offset, i);
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Ball<N> {
+    type Item = Coord<isize, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.offset?;
+
+        let mut offset = current;
+        self.offset = (0..N).rev().find_map(|i| {
+            (offset[i] < self.limit(&offset, i)).then(|| {
+                offset[i] += 1;
+                self.reset_from(&mut offset, i + 1);
+                offset
+            })
+        });
+
+        Some(self.center + Coord(current))
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for Coord<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, T::add)
+    }
+}
+
+impl<const N: usize> Add<Coord<isize, N>> for Coord<usize, N> {
+    type Output = Coord<isize, N>;
+
+    #[inline(always)]
+    fn add(self, rhs: Coord<isize, N>) -> Self::Output {
+        self.zip_with(rhs, |a, b| a as isize + b)
+    }
+}
+
+impl<T: Copy + AddAssign, const N: usize> AddAssign for Coord<T, N> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for Coord<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, T::sub)
+    }
+}
+
+impl<T: Copy + SubAssign, const N: usize> SubAssign for Coord<T, N> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for Coord<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.map(T::neg)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for Coord<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|v| v * rhs)
+    }
+}
+
+impl<T: Copy + Div<Output = T>, const N: usize> Div<T> for Coord<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|v| v / rhs)
+    }
+}
+
+impl<const N: usize> TryFrom<Coord<isize, N>> for Coord<usize, N> {
+    type Error = Errors;
+
+    #[inline(always)]
+    fn try_from(val: Coord<isize, N>) -> Result<Self, Self::Error> {
+        let mut result = [0; N];
+        for (r, v) in result.iter_mut().zip(val.0) {
+            *r = v.try_into().map_err(|_| Errors::ConversionError)?;
+        }
+        Ok(Coord(result))
+    }
+}
+
+impl<const N: usize> TryFrom<Coord<usize, N>> for Coord<isize, N> {
+    type Error = Errors;
+
+    #[inline(always)]
+    fn try_from(val: Coord<usize, N>) -> Result<Self, Self::Error> {
+        let mut result = [0; N];
+        for (r, v) in result.iter_mut().zip(val.0) {
+            *r = v.try_into().map_err(|_| Errors::ConversionError)?;
+        }
+        Ok(Coord(result))
+    }
+}
+
+pub trait TryAsUCoord<const N: usize> {
+    fn try_as_ucoord(self) -> Result<Coord<usize, N>, Errors>;
+}
+
+impl<const N: usize> TryAsUCoord<N> for Coord<usize, N> {
+    #[inline(always)]
+    fn try_as_ucoord(self) -> Result<Coord<usize, N>, Errors> {
+        Ok(self)
+    }
+}
+
+impl<const N: usize> TryAsUCoord<N> for Coord<isize, N> {
+    #[inline(always)]
+    fn try_as_ucoord(self) -> Result<Coord<usize, N>, Errors> {
+        self.try_into()
+    }
+}
+
+pub trait TryAsICoord<const N: usize> {
+    fn try_as_icoord(self) -> Result<Coord<isize, N>, Errors>;
+}
+
+impl<const N: usize> TryAsICoord<N> for Coord<isize, N> {
+    #[inline(always)]
+    fn try_as_icoord(self) -> Result<Coord<isize, N>, Errors> {
+        Ok(self)
+    }
+}
+
+impl<const N: usize> TryAsICoord<N> for Coord<usize, N> {
+    #[inline(always)]
+    fn try_as_icoord(self) -> Result<Coord<isize, N>, Errors> {
+        self.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_is_component_wise() {
+        let a = ICoord3D::new(1, -2, 3);
+        let b = ICoord3D::new(-4, 5, 0);
+
+        assert_eq!(a + b, ICoord3D::new(-3, 3, 3));
+        assert_eq!(a - b, ICoord3D::new(5, -7, 3));
+        assert_eq!(-a, ICoord3D::new(-1, 2, -3));
+        assert_eq!(a * 2, ICoord3D::new(2, -4, 6));
+        assert_eq!(b / 2, ICoord3D::new(-2, 2, 0));
+        assert_eq!(a.component_min(b), ICoord3D::new(-4, -2, 0));
+        assert_eq!(a.component_max(b), ICoord3D::new(1, 5, 3));
+        assert_eq!(b.abs(), ICoord3D::new(4, 5, 0));
+        assert_eq!(b.signum(), ICoord3D::new(-1, 1, 0));
+    }
+
+    #[test]
+    fn named_components_access_the_array() {
+        let mut coord = UCoord4D::new(1, 2, 3, 4);
+        *coord.w_mut() += 10;
+        *coord.x_mut() = 0;
+
+        assert_eq!(coord.0, [0, 2, 3, 14]);
+        assert_eq!((coord.y(), coord.z()), (2, 3));
+        assert_eq!(ICoord2D::new(-5, 6).x(), -5);
+        assert_eq!(coord.try_as_icoord().unwrap(), ICoord4D::new(0, 2, 3, 14));
+        assert!(ICoord2D::new(1, -1).try_as_ucoord().is_err());
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("3,-4".parse::<ICoord2D>().unwrap(), ICoord2D::new(3, -4));
+        assert_eq!(
+            " 1, 2 ,3".parse::<UCoord3D>().unwrap(),
+            UCoord3D::new(1, 2, 3)
+        );
+        assert_eq!(
+            UCoord2D::parse_with_separator("5x7", "x").unwrap(),
+            UCoord2D::new(5, 7)
+        );
+        assert!("1,2,3".parse::<UCoord2D>().is_err());
+        assert!("1".parse::<UCoord2D>().is_err());
+        assert!("1,-2".parse::<UCoord2D>().is_err());
+
+        assert_eq!(ICoord3D::new(1, -2, 3).to_string(), "1,-2,3");
+        assert_eq!(UCoord2D::new(5, 7).display_with(" ").to_string(), "5 7");
+    }
+
+    #[test]
+    fn distances() {
+        let a = ICoord3D::new(1, -2, 3);
+        let b = ICoord3D::new(-4, 5, 3);
+
+        assert_eq!(a.manhattan(b), 12);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(a.euclidean_sq(b), 74);
+        assert_eq!(UCoord2D::new(7, 1).manhattan(UCoord2D::new(2, 4)), 8);
+    }
+
+    #[test]
+    fn balls_hold_exactly_the_points_within_the_radius() {
+        let center = ICoord2D::new(3, -1);
+        let cube = (-5..=5).flat_map(|y| (-5..=5).map(move |x| center + ICoord2D::new(x, y)));
+
+        for radius in 0..4 {
+            let manhattan = center.within_manhattan(radius).collect::<Vec<_>>();
+            let expected = cube.clone().filter(|p| p.manhattan(center) <= radius);
+            assert_eq!(manhattan.len(), 2 * radius * (radius + 1) + 1);
+            assert!(expected.clone().all(|p| manhattan.contains(&p)));
+
+            let chebyshev = center.within_chebyshev(radius).collect::<Vec<_>>();
+            assert_eq!(chebyshev.len(), (2 * radius + 1).pow(2));
+            assert!(chebyshev.iter().all(|p| p.chebyshev(center) <= radius));
+        }
+
+        assert_eq!(ICoord3D::default().within_manhattan(2).count(), 25);
+    }
+
+    #[test]
+    fn wrapping_stays_on_the_torus() {
+        let dim = ICoord2D::new(4, 3);
+
+        assert_eq!(ICoord2D::new(-1, -4).rem_euclid(dim), ICoord2D::new(3, 2));
+        assert_eq!(ICoord2D::new(9, 3).rem_euclid(dim), ICoord2D::new(1, 0));
+        assert_eq!(ICoord2D::new(2, 1).rem_euclid(dim), ICoord2D::new(2, 1));
+
+        let start = ICoord2D::new(3, 0);
+        assert_eq!(
+            start.wrapping_add(ICoord2D::new(1, -1), dim),
+            ICoord2D::new(0, 2)
+        );
+        assert_eq!(
+            start.wrapping_add(ICoord2D::new(-11, 7), dim),
+            ICoord2D::new(0, 1)
+        );
+    }
+}
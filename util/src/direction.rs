@@ -1,5 +1,7 @@
-use crate::coord2d::ICoord2D;
+use crate::coord::ICoord2D;
 use crate::direction::Direction::{East, North, South, West};
+use crate::error::Errors;
+use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Direction {
@@ -13,10 +15,10 @@ impl Direction {
     #[inline(always)]
     pub fn to_offset(self) -> ICoord2D {
         match self {
-            North => ICoord2D { x: 0, y: -1 },
-            East => ICoord2D { x: 1, y: 0 },
-            South => ICoord2D { x: 0, y: 1 },
-            West => ICoord2D { x: -1, y: 0 },
+            North => ICoord2D::new(0, -1),
+            East => ICoord2D::new(1, 0),
+            South => ICoord2D::new(0, 1),
+            West => ICoord2D::new(-1, 0),
         }
     }
 
@@ -45,3 +47,58 @@ impl Direction {
         [North, East, South, West]
     }
 }
+
+// Accepts the arrows `^>v<`, the compass letters `NESW` and the screen letters `URDL`.
+impl TryFrom<char> for Direction {
+    type Error = Errors;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(North),
+            '>' | 'E' | 'R' => Ok(East),
+            'v' | 'S' | 'D' => Ok(South),
+            '<' | 'W' | 'L' => Ok(West),
+            _ => Err(Errors::ParseError),
+        }
+    }
+}
+
+// Drawn as the arrow `TryFrom<char>` accepts, as seen in grid renderings.
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            North => '^',
+            East => '>',
+            South => 'v',
+            West => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chars_round_trip() {
+        for dir in Direction::directions() {
+            let arrow = dir.to_string().chars().next().unwrap();
+            assert_eq!(Direction::try_from(arrow).unwrap(), dir);
+        }
+
+        assert_eq!(
+            Direction::directions().map(|d| d.to_string()).concat(),
+            "^>v<"
+        );
+        for (letters, expected) in ["NESW", "URDL"]
+            .into_iter()
+            .zip([Direction::directions(); 2])
+        {
+            let parsed = letters.chars().map(Direction::try_from);
+            assert_eq!(parsed.collect::<Result<Vec<_>, _>>().unwrap(), expected);
+        }
+        assert!(Direction::try_from('x').is_err());
+        assert!(Direction::try_from('n').is_err());
+    }
+}
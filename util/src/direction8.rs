@@ -1,7 +1,10 @@
-use crate::coord2d::ICoord2D;
+use crate::coord::ICoord2D;
 use crate::direction8::Direction8::{
     East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
 };
+use crate::error::Errors;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Direction8 {
@@ -19,14 +22,14 @@ impl Direction8 {
     #[inline(always)]
     pub fn to_offset(self) -> ICoord2D {
         match self {
-            North => ICoord2D { x: 0, y: -1 },
-            NorthEast => ICoord2D { x: 1, y: -1 },
-            East => ICoord2D { x: 1, y: 0 },
-            SouthEast => ICoord2D { x: 1, y: 1 },
-            South => ICoord2D { x: 0, y: 1 },
-            SouthWest => ICoord2D { x: -1, y: 1 },
-            West => ICoord2D { x: -1, y: 0 },
-            NorthWest => ICoord2D { x: -1, y: -1 },
+            North => ICoord2D::new(0, -1),
+            NorthEast => ICoord2D::new(1, -1),
+            East => ICoord2D::new(1, 0),
+            SouthEast => ICoord2D::new(1, 1),
+            South => ICoord2D::new(0, 1),
+            SouthWest => ICoord2D::new(-1, 1),
+            West => ICoord2D::new(-1, 0),
+            NorthWest => ICoord2D::new(-1, -1),
         }
     }
 
@@ -58,6 +61,20 @@ impl Direction8 {
         }
     }
 
+    // The compass abbreviation, `N`, `NE`, `E` and so on.
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            North => "N",
+            NorthEast => "NE",
+            East => "E",
+            SouthEast => "SE",
+            South => "S",
+            SouthWest => "SW",
+            West => "W",
+            NorthWest => "NW",
+        }
+    }
+
     #[inline(always)]
     pub const fn directions() -> [Direction8; 8] {
         [
@@ -65,3 +82,38 @@ impl Direction8 {
         ]
     }
 }
+
+impl FromStr for Direction8 {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::directions()
+            .into_iter()
+            .find(|dir| dir.abbreviation() == s)
+            .ok_or(Errors::ParseError)
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abbreviations_round_trip() {
+        for dir in Direction8::directions() {
+            assert_eq!(dir.to_string().parse::<Direction8>().unwrap(), dir);
+        }
+
+        assert_eq!("SW".parse::<Direction8>().unwrap(), SouthWest);
+        assert_eq!(NorthEast.to_string(), "NE");
+        for bad in ["", "n", "NN", "EN", "North"] {
+            assert!(bad.parse::<Direction8>().is_err(), "{bad:?}");
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Union-find over arbitrary items, with path compression and union by size. Items are added
+// as singletons, either up front or the first time they are united.
+#[derive(Clone, Debug)]
+pub struct DisjointSet<T> {
+    index: HashMap<T, usize>,
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl<T> Default for DisjointSet<T> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            parent: Vec::new(),
+            size: Vec::new(),
+            components: 0,
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> DisjointSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, item: &T) -> bool {
+        self.index.contains_key(item)
+    }
+
+    // Adds `item` as a component of its own, returning false if it is already present.
+    pub fn insert(&mut self, item: T) -> bool {
+        if self.index.contains_key(&item) {
+            return false;
+        }
+        self.slot(&item);
+        true
+    }
+
+    fn slot(&mut self, item: &T) -> usize {
+        if let Some(&idx) = self.index.get(item) {
+            return idx;
+        }
+
+        let idx = self.parent.len();
+        self.index.insert(item.clone(), idx);
+        self.parent.push(idx);
+        self.size.push(1);
+        self.components += 1;
+        idx
+    }
+
+    fn root(&mut self, mut idx: usize) -> usize {
+        // Path halving: every other node on the way up skips to its grandparent.
+        while self.parent[idx] != idx {
+            self.parent[idx] = self.parent[self.parent[idx]];
+            idx = self.parent[idx];
+        }
+        idx
+    }
+
+    // An identifier of the component holding `item`, shared by all of its members until the
+    // next union.
+    pub fn find(&mut self, item: &T) -> Option<usize> {
+        let idx = *self.index.get(item)?;
+        Some(self.root(idx))
+    }
+
+    // Merges the components of `a` and `b`, adding them if needed. Returns false if they were
+    // already in the same component.
+    pub fn union(&mut self, a: &T, b: &T) -> bool {
+        let a = self.slot(a);
+        let b = self.slot(b);
+        let (mut a, mut b) = (self.root(a), self.root(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.find(a), self.find(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    pub fn component_size(&mut self, item: &T) -> Option<usize> {
+        let root = self.find(item)?;
+        Some(self.size[root])
+    }
+
+    // The sizes of all components, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&idx| self.parent[idx] == idx)
+            .map(|idx| self.size[idx])
+            .collect()
+    }
+
+    // The sizes of the `k` largest components, largest first.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes = self.component_sizes();
+        let k = k.min(sizes.len());
+        if k > 0 && k < sizes.len() {
+            sizes.select_nth_unstable_by(k - 1, |a, b| b.cmp(a));
+        }
+        sizes.truncate(k);
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+impl<T: Hash + Eq + Clone> FromIterator<T> for DisjointSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_merge_components() {
+        let mut set = (0..10).collect::<DisjointSet<_>>();
+        assert_eq!(set.component_count(), 10);
+
+        assert!(set.union(&0, &1));
+        assert!(set.union(&2, &3));
+        assert!(set.union(&1, &3));
+        assert!(!set.union(&0, &2));
+        assert!(set.union(&5, &6));
+        assert!(set.union(&10, &11));
+
+        assert_eq!(set.len(), 12);
+        assert_eq!(set.component_count(), 7);
+        assert!(set.connected(&0, &3));
+        assert!(!set.connected(&0, &5));
+        assert!(!set.connected(&0, &42));
+        assert_eq!(set.component_size(&2), Some(4));
+        assert_eq!(set.component_sizes().iter().sum::<usize>(), 12);
+        assert_eq!(set.largest(3), [4, 2, 2]);
+        assert_eq!(set.largest(20).len(), 7);
+        assert!(set.largest(0).is_empty());
+    }
+}
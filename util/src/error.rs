@@ -1,5 +1,10 @@
+use crate::coord::UCoord2D;
+use std::path::PathBuf;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Errors {
+    #[error("IoError({0})")]
+    IoError(#[from] std::io::Error),
     #[error("ParseIntError({0})")]
     ParseIntError(#[from] std::num::ParseIntError),
     #[error("ParseError")]
@@ -10,4 +15,20 @@ pub enum Errors {
     UncategorizedError(String),
     #[error("ConversionError")]
     ConversionError,
+    #[error("InputNotFound(tried {})", display_paths(.0))]
+    InputNotFound(Vec<PathBuf>),
+    #[error("FetchError({0})")]
+    FetchError(String),
+    #[error("NotUnlocked({0} day {1:02})")]
+    NotUnlocked(u16, u8),
+    #[error("GridParseError(at {0:?}: {1})")]
+    GridParseError(UCoord2D, Box<Errors>),
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
@@ -1,6 +1,24 @@
-pub use crate::coord2d::TryAsUCoord2D;
-pub use crate::coord2d::UCoord2D;
+pub use crate::coord::TryAsICoord;
+pub use crate::coord::TryAsUCoord;
+pub use crate::coord::UCoord2D;
+use crate::coord::{Coord, ICoord2D};
+use crate::direction::Direction;
+use crate::direction8::Direction8;
 pub use crate::error::Errors;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+// How a cell is drawn when a grid is displayed.
+pub trait CellChar {
+    fn to_char(&self) -> char;
+}
+
+impl CellChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UGrid2D<T> {
@@ -11,29 +29,34 @@ pub struct UGrid2D<T> {
 impl<T: Default + Clone> UGrid2D<T> {
     #[inline(always)]
     pub fn from_default(dim: UCoord2D) -> Self {
-        Self::generate(dim, |_| Ok(T::default())).unwrap()
+        Self {
+            dim,
+            data: vec![T::default(); dim.x() * dim.y()],
+        }
     }
 }
 
 impl<T: Clone> UGrid2D<T> {
     #[inline(always)]
-    fn internal_index(&self, coord: impl TryAsUCoord2D) -> Result<usize, Errors> {
-        let coord = coord.try_as_uucord2d()?;
+    fn internal_index(&self, coord: impl TryAsUCoord<2>) -> Result<usize, Errors> {
+        let coord = coord.try_as_ucoord()?;
         match coord {
-            UCoord2D { x, y: _ } if x >= self.dim.x => Err(Errors::DimError(format!(
+            Coord([x, _]) if x >= self.dim.x() => Err(Errors::DimError(format!(
                 "x ({}) equal or above dimensional bound ({})",
-                x, self.dim.x
+                x,
+                self.dim.x()
             ))),
-            UCoord2D { x: _, y } if y >= self.dim.y => Err(Errors::DimError(format!(
+            Coord([_, y]) if y >= self.dim.y() => Err(Errors::DimError(format!(
                 "y ({}) equal or above dimensional bound ({})",
-                y, self.dim.y
+                y,
+                self.dim.y()
             ))),
-            UCoord2D { x, y } => Ok(x + self.dim.x * y),
+            Coord([x, y]) => Ok(x + self.dim.x() * y),
         }
     }
 
     #[inline(always)]
-    pub fn get(&self, coord: impl TryAsUCoord2D) -> Result<&T, Errors> {
+    pub fn get(&self, coord: impl TryAsUCoord<2>) -> Result<&T, Errors> {
         let idx = self.internal_index(coord)?;
         self.data
             .get(idx)
@@ -41,63 +64,281 @@ impl<T: Clone> UGrid2D<T> {
     }
 
     #[inline(always)]
-    pub fn get_mut(&mut self, coord: impl TryAsUCoord2D) -> Result<&mut T, Errors> {
+    pub fn get_mut(&mut self, coord: impl TryAsUCoord<2>) -> Result<&mut T, Errors> {
         let idx = self.internal_index(coord)?;
         self.data
             .get_mut(idx)
             .ok_or(Errors::DimError("Unexpected dim error".into()))
     }
 
+    // Coordinates outside of the grid wrap around its edges, as if it were a torus.
+    #[inline(always)]
+    pub fn wrap(&self, coord: impl TryAsICoord<2>) -> Result<UCoord2D, Errors> {
+        if self.data.is_empty() {
+            return Err(Errors::DimError("Cannot wrap around an empty grid".into()));
+        }
+        coord
+            .try_as_icoord()?
+            .rem_euclid(self.dim.try_as_icoord()?)
+            .try_as_ucoord()
+    }
+
+    #[inline(always)]
+    pub fn get_wrapping(&self, coord: impl TryAsICoord<2>) -> Result<&T, Errors> {
+        self.get(self.wrap(coord)?)
+    }
+
+    #[inline(always)]
+    pub fn get_wrapping_mut(&mut self, coord: impl TryAsICoord<2>) -> Result<&mut T, Errors> {
+        self.get_mut(self.wrap(coord)?)
+    }
+
+    // Moves from `coord` by `offset`, coming back in on the other side when leaving the grid.
+    #[inline(always)]
+    pub fn step_wrapping(&self, coord: UCoord2D, offset: ICoord2D) -> Result<UCoord2D, Errors> {
+        self.wrap(coord + offset)
+    }
+
     #[inline(always)]
     pub fn generate<F: FnMut(UCoord2D) -> Result<T, Errors>>(
         dim: UCoord2D,
         mut f: F,
     ) -> Result<Self, Errors> {
-        let mut data: Vec<T> = Vec::with_capacity(dim.x * dim.y);
-        for iy in 0..dim.y {
-            for ix in 0..dim.x {
-                let coord = UCoord2D { x: ix, y: iy };
+        let mut data: Vec<T> = Vec::with_capacity(dim.x() * dim.y());
+        for iy in 0..dim.y() {
+            for ix in 0..dim.x() {
+                let coord = UCoord2D::new(ix, iy);
                 data.push(f(coord)?)
             }
         }
         Ok(Self { dim, data })
     }
 
-    #[inline(always)]
+    // One row per line, mapping every character with `f`. Errors, including lines that do not
+    // match the width of the first one, carry the coordinate they occurred at.
+    pub fn parse<F: FnMut(char, UCoord2D) -> Result<T, Errors>>(
+        input: &str,
+        mut f: F,
+    ) -> Result<Self, Errors> {
+        let ragged = |coord: UCoord2D, len: usize, width: usize| {
+            Errors::GridParseError(
+                coord,
+                Box::new(Errors::DimError(format!(
+                    "line has {len} characters, expected {width}"
+                ))),
+            )
+        };
+
+        let mut data = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let len = line.chars().count();
+            let width = *width.get_or_insert(len);
+
+            for (x, c) in line.chars().enumerate() {
+                let coord = UCoord2D::new(x, y);
+                if x >= width {
+                    return Err(ragged(coord, len, width));
+                }
+                data.push(
+                    f(c, coord).map_err(|error| Errors::GridParseError(coord, Box::new(error)))?,
+                );
+            }
+
+            if len < width {
+                return Err(ragged(UCoord2D::new(len, y), len, width));
+            }
+            height = y + 1;
+        }
+
+        Ok(Self {
+            dim: UCoord2D::new(width.unwrap_or(0), height),
+            data,
+        })
+    }
+
+    // Like `parse`, additionally recording where each of the `markers` was found. Markers are
+    // still handed to `f`, which decides what lies underneath them.
+    pub fn parse_with_markers<F: FnMut(char, UCoord2D) -> Result<T, Errors>>(
+        input: &str,
+        markers: &[char],
+        mut f: F,
+    ) -> Result<(Self, HashMap<char, UCoord2D>), Errors> {
+        let mut found = HashMap::with_capacity(markers.len());
+
+        let grid = Self::parse(input, |c, coord| {
+            if markers.contains(&c) && found.insert(c, coord).is_some() {
+                return Err(Errors::UncategorizedError(format!(
+                    "marker {c} appears more than once"
+                )));
+            }
+            f(c, coord)
+        })?;
+
+        Ok((grid, found))
+    }
+
+    // Draws every cell with `f`. The result displays as one line per row and takes overlays.
+    pub fn render_with<F: FnMut(&T, UCoord2D) -> char>(&self, mut f: F) -> UGrid2D<char> {
+        UGrid2D {
+            dim: self.dim,
+            data: self.iter().map(|(coord, cell)| f(cell, coord)).collect(),
+        }
+    }
+
+    // Builds a grid of `dim` whose cells are taken from the old coordinates `source` maps to.
+    fn remap(&self, dim: UCoord2D, source: impl Fn(UCoord2D) -> UCoord2D) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..dim.y() {
+            for x in 0..dim.x() {
+                let Coord([old_x, old_y]) = source(UCoord2D::new(x, y));
+                data.push(self.data[old_x + self.dim.x() * old_y].clone());
+            }
+        }
+        Self { dim, data }
+    }
+
+    // Rotations are as seen on screen, with y pointing down: `rotate_left` turns the top row
+    // into the left column, like `Direction::rotate_left` turns north into west.
+    // Counter-clockwise on screen; before the rotations were reworked it turned clockwise.
     pub fn rotate_left(&self) -> Self {
-        Self::generate(
-            UCoord2D {
-                x: self.dim.y,
-                y: self.dim.x,
-            },
-            |UCoord2D { x: new_x, y: new_y }| {
-                let old_coord = UCoord2D {
-                    x: new_y,
-                    y: self.dim.x - new_x - 1,
-                };
-                Ok(self.get(old_coord)?.to_owned())
-            },
-        )
-        .unwrap()
+        let Coord([w, h]) = self.dim;
+        self.remap(UCoord2D::new(h, w), |Coord([x, y])| {
+            UCoord2D::new(w - 1 - y, x)
+        })
     }
 
-    #[inline(always)]
-    pub fn flip_horizontal_axis(&self) -> Self {
-        Self::generate(self.dim, |coord| {
-            Ok(self
-                .get(UCoord2D {
-                    x: coord.x,
-                    y: self.dim.y - coord.y - 1,
-                })?
-                .to_owned())
+    // Clockwise on screen.
+    pub fn rotate_right(&self) -> Self {
+        let Coord([w, h]) = self.dim;
+        self.remap(UCoord2D::new(h, w), |Coord([x, y])| {
+            UCoord2D::new(y, h - 1 - x)
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let Coord([w, h]) = self.dim;
+        self.remap(self.dim, |Coord([x, y])| {
+            UCoord2D::new(w - 1 - x, h - 1 - y)
         })
-        .unwrap()
+    }
+
+    // Mirrors along the main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let Coord([w, h]) = self.dim;
+        self.remap(UCoord2D::new(h, w), |Coord([x, y])| UCoord2D::new(y, x))
+    }
+
+    // Mirrors along the anti-diagonal.
+    pub fn anti_transpose(&self) -> Self {
+        let Coord([w, h]) = self.dim;
+        self.remap(UCoord2D::new(h, w), |Coord([x, y])| {
+            UCoord2D::new(w - 1 - y, h - 1 - x)
+        })
+    }
+
+    // Swaps top and bottom.
+    pub fn flip_horizontal_axis(&self) -> Self {
+        let h = self.dim.y();
+        self.remap(self.dim, |Coord([x, y])| UCoord2D::new(x, h - 1 - y))
+    }
+
+    // Swaps left and right.
+    pub fn flip_vertical_axis(&self) -> Self {
+        let w = self.dim.x();
+        self.remap(self.dim, |Coord([x, y])| UCoord2D::new(w - 1 - x, y))
+    }
+
+    // The grid under all 8 symmetries of the square: the identity, three rotations and four
+    // reflections. Symmetric shapes show up more than once.
+    pub fn symmetries(&self) -> [Self; 8] {
+        [
+            self.clone(),
+            self.rotate_left(),
+            self.rotate_180(),
+            self.rotate_right(),
+            self.flip_horizontal_axis(),
+            self.flip_vertical_axis(),
+            self.transpose(),
+            self.anti_transpose(),
+        ]
     }
 
     #[inline(always)]
     pub fn sub_grid(&self, start: UCoord2D, dim: UCoord2D) -> Result<Self, Errors> {
         Self::generate(dim, |new_coord| Ok(self.get(start + new_coord)?.clone()))
     }
+}
+
+impl<T> UGrid2D<T> {
+    // The in-place transforms move cells around without cloning them. Counter-clockwise on
+    // screen, as `rotate_left`.
+    pub fn rotate_left_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_horizontal_axis_in_place();
+    }
+
+    // Clockwise on screen, as `rotate_right`.
+    pub fn rotate_right_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_vertical_axis_in_place();
+    }
+
+    pub fn rotate_180_in_place(&mut self) {
+        self.data.reverse();
+    }
+
+    // Non-square grids are transposed by following the cycles of the index permutation,
+    // square ones by swapping across the diagonal.
+    pub fn transpose_in_place(&mut self) {
+        let Coord([w, h]) = self.dim;
+
+        if w == h {
+            for y in 0..h {
+                for x in y + 1..w {
+                    self.data.swap(x + w * y, y + w * x);
+                }
+            }
+        } else {
+            let target = |idx: usize| (idx % w) * h + idx / w;
+            let mut placed = vec![false; self.data.len()];
+
+            for start in 0..self.data.len() {
+                let mut idx = start;
+                while !placed[idx] {
+                    placed[idx] = true;
+                    let next = target(idx);
+                    if next != start {
+                        self.data.swap(start, next);
+                    }
+                    idx = next;
+                }
+            }
+        }
+
+        self.dim = UCoord2D::new(h, w);
+    }
+
+    pub fn anti_transpose_in_place(&mut self) {
+        self.transpose_in_place();
+        self.rotate_180_in_place();
+    }
+
+    pub fn flip_horizontal_axis_in_place(&mut self) {
+        let Coord([w, h]) = self.dim;
+        for y in 0..h / 2 {
+            let (top, bottom) = self.data.split_at_mut((h - 1 - y) * w);
+            top[y * w..(y + 1) * w].swap_with_slice(&mut bottom[..w]);
+        }
+    }
+
+    pub fn flip_vertical_axis_in_place(&mut self) {
+        for row in self.data.chunks_mut(self.dim.x().max(1)) {
+            row.reverse();
+        }
+    }
 
     #[inline(always)]
     pub fn dim(&self) -> UCoord2D {
@@ -108,9 +349,680 @@ impl<T: Clone> UGrid2D<T> {
     pub fn coord_to_grid(&self, coord: impl TryInto<UCoord2D>) -> Option<UCoord2D> {
         let candidate: Option<UCoord2D> = coord.try_into().ok();
         match candidate {
-            Some(UCoord2D { x, y: _ }) if x >= self.dim.x => None,
-            Some(UCoord2D { x: _, y }) if y >= self.dim.y => None,
+            Some(Coord([x, _])) if x >= self.dim.x() => None,
+            Some(Coord([_, y])) if y >= self.dim.y() => None,
             val => val,
         }
     }
+
+    #[inline(always)]
+    fn index_to_coord(&self, idx: usize) -> UCoord2D {
+        UCoord2D::new(idx % self.dim.x(), idx / self.dim.x())
+    }
+
+    // Cells in row-major order, together with their coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = (UCoord2D, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.index_to_coord(idx), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (UCoord2D, &mut T)> {
+        let dim = self.dim;
+        self.data.iter_mut().enumerate().map(move |(idx, cell)| {
+            let coord = UCoord2D::new(idx % dim.x(), idx / dim.x());
+            (coord, cell)
+        })
+    }
+
+    fn neighbors(
+        &self,
+        coord: UCoord2D,
+        offsets: impl Iterator<Item = ICoord2D>,
+    ) -> impl Iterator<Item = (UCoord2D, &T)> {
+        offsets.filter_map(move |offset| {
+            let neighbor = self.coord_to_grid(coord + offset)?;
+            Some((
+                neighbor,
+                &self.data[neighbor.x() + self.dim.x() * neighbor.y()],
+            ))
+        })
+    }
+
+    // The orthogonal neighbors of `coord` that lie inside the grid, in `Direction` order.
+    pub fn neighbors4(&self, coord: UCoord2D) -> impl Iterator<Item = (UCoord2D, &T)> {
+        self.neighbors(
+            coord,
+            Direction::directions()
+                .into_iter()
+                .map(Direction::to_offset),
+        )
+    }
+
+    // Like `neighbors4`, including the diagonals, in `Direction8` order.
+    pub fn neighbors8(&self, coord: UCoord2D) -> impl Iterator<Item = (UCoord2D, &T)> {
+        self.neighbors(
+            coord,
+            Direction8::directions()
+                .into_iter()
+                .map(Direction8::to_offset),
+        )
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.dim.x().max(1)).take(self.dim.y())
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.dim.x()).map(move |x| self.data.iter().skip(x).step_by(self.dim.x()))
+    }
+
+    // The coordinate of the first cell in row-major order matching `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<UCoord2D> {
+        self.data
+            .iter()
+            .position(predicate)
+            .map(|idx| self.index_to_coord(idx))
+    }
+}
+
+// Labels from `connected_components`, numbered from 0 in row-major order of the regions'
+// first cells.
+pub type RegionId = usize;
+
+impl<T> UGrid2D<T> {
+    // Breadth-first search over orthogonal steps from `start`. A step is taken when
+    // `passable(from, to)` holds for the cells on either side of it. Unreached cells are `None`.
+    pub fn bfs_distances(
+        &self,
+        start: UCoord2D,
+        mut passable: impl FnMut(&T, &T) -> bool,
+    ) -> Result<UGrid2D<Option<usize>>, Errors> {
+        let start = self.checked_index(start)?;
+
+        let mut distances = vec![None; self.data.len()];
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(idx) = queue.pop_front() {
+            let next_dist = distances[idx].map(|dist| dist + 1);
+            for (neighbor, cell) in self.neighbors4(self.index_to_coord(idx)) {
+                let neighbor = neighbor.x() + self.dim.x() * neighbor.y();
+                if distances[neighbor].is_none() && passable(&self.data[idx], cell) {
+                    distances[neighbor] = next_dist;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        Ok(UGrid2D {
+            dim: self.dim,
+            data: distances,
+        })
+    }
+
+    // The cells reachable from `start` over orthogonal steps for which `same_region(from, to)`
+    // holds, in breadth-first order starting with `start` itself.
+    pub fn flood_fill(
+        &self,
+        start: UCoord2D,
+        mut same_region: impl FnMut(&T, &T) -> bool,
+    ) -> Result<Vec<UCoord2D>, Errors> {
+        let start = self.checked_index(start)?;
+
+        let mut visited = vec![false; self.data.len()];
+        visited[start] = true;
+        let mut region = vec![self.index_to_coord(start)];
+        let mut next = 0;
+
+        while let Some(&coord) = region.get(next) {
+            next += 1;
+            let cell = &self.data[coord.x() + self.dim.x() * coord.y()];
+            for (neighbor, neighbor_cell) in self.neighbors4(coord) {
+                let idx = neighbor.x() + self.dim.x() * neighbor.y();
+                if !visited[idx] && same_region(cell, neighbor_cell) {
+                    visited[idx] = true;
+                    region.push(neighbor);
+                }
+            }
+        }
+
+        Ok(region)
+    }
+
+    // Labels every cell with its region, the orthogonally connected cells for which
+    // `same_region` holds between neighbors. `same_region` should be symmetric.
+    pub fn connected_components_by(
+        &self,
+        mut same_region: impl FnMut(&T, &T) -> bool,
+    ) -> UGrid2D<RegionId> {
+        let mut labels = vec![None; self.data.len()];
+        let mut next_id = 0;
+        let mut queue = Vec::new();
+
+        for start in 0..self.data.len() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            labels[start] = Some(next_id);
+            queue.push(start);
+            while let Some(idx) = queue.pop() {
+                for (neighbor, cell) in self.neighbors4(self.index_to_coord(idx)) {
+                    let neighbor = neighbor.x() + self.dim.x() * neighbor.y();
+                    if labels[neighbor].is_none() && same_region(&self.data[idx], cell) {
+                        labels[neighbor] = Some(next_id);
+                        queue.push(neighbor);
+                    }
+                }
+            }
+            next_id += 1;
+        }
+
+        UGrid2D {
+            dim: self.dim,
+            data: labels.into_iter().flatten().collect(),
+        }
+    }
+
+    // The regions of `connected_components_by`, ordered by id.
+    pub fn regions_by(&self, same_region: impl FnMut(&T, &T) -> bool) -> Vec<Region> {
+        let labels = Arc::new(self.connected_components_by(same_region));
+
+        let mut regions: Vec<Region> = Vec::new();
+        for (coord, &id) in labels.iter() {
+            if id == regions.len() {
+                regions.push(Region {
+                    id,
+                    cells: Vec::new(),
+                    min: coord,
+                    max: coord,
+                    labels: labels.clone(),
+                });
+            }
+            let region = &mut regions[id];
+            region.cells.push(coord);
+            region.min = region.min.component_min(coord);
+            region.max = region.max.component_max(coord);
+        }
+
+        regions
+    }
+
+    #[inline(always)]
+    fn checked_index(&self, coord: UCoord2D) -> Result<usize, Errors> {
+        let coord = self
+            .coord_to_grid(coord)
+            .ok_or(Errors::DimError(format!("{coord} outside of grid")))?;
+        Ok(coord.x() + self.dim.x() * coord.y())
+    }
+}
+
+impl<T: PartialEq> UGrid2D<T> {
+    // Regions of orthogonally connected, equal cells.
+    pub fn connected_components(&self) -> UGrid2D<RegionId> {
+        self.connected_components_by(T::eq)
+    }
+
+    #[inline(always)]
+    pub fn regions(&self) -> Vec<Region> {
+        self.regions_by(T::eq)
+    }
+}
+
+// A connected region of a grid, as labeled by `connected_components`. The labels are shared
+// by all regions of the grid, so looking up whether a cell belongs to one is cheap.
+#[derive(Clone, Debug)]
+pub struct Region {
+    id: RegionId,
+    cells: Vec<UCoord2D>,
+    min: UCoord2D,
+    max: UCoord2D,
+    labels: Arc<UGrid2D<RegionId>>,
+}
+
+impl Region {
+    #[inline(always)]
+    pub fn id(&self) -> RegionId {
+        self.id
+    }
+
+    // The cells in row-major order.
+    #[inline(always)]
+    pub fn cells(&self) -> &[UCoord2D] {
+        &self.cells
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, coord: impl TryAsUCoord<2>) -> bool {
+        self.labels.get(coord).is_ok_and(|&id| id == self.id)
+    }
+
+    #[inline(always)]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // The smallest and largest corner, both inclusive.
+    #[inline(always)]
+    pub fn bounding_box(&self) -> (UCoord2D, UCoord2D) {
+        (self.min, self.max)
+    }
+
+    // The number of cell edges between the region and anything else, the grid border included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&cell| {
+                Direction::directions()
+                    .into_iter()
+                    .filter(|dir| !self.contains(cell + dir.to_offset()))
+                    .count()
+            })
+            .sum()
+    }
+
+    // The corners of the outline, convex and concave ones, holes included. A corner where two
+    // cells of the region touch diagonally counts once for each of them.
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&cell| {
+                Direction::directions()
+                    .into_iter()
+                    .filter(|&dir| {
+                        let a = dir.to_offset();
+                        let b = dir.rotate_right().to_offset();
+                        match (self.contains(cell + a), self.contains(cell + b)) {
+                            (false, false) => true,
+                            (true, true) => !self.contains(cell + a + b),
+                            _ => false,
+                        }
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    // Every straight side of the outline ends in a corner, so there are as many of each.
+    #[inline(always)]
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    // The groups of cells that the region encloses, each in row-major order. Enclosed cells
+    // are grouped by shared edges, so they cannot escape between diagonally touching cells of
+    // the region, and cells only touching each other diagonally are separate holes.
+    pub fn holes(&self) -> Vec<Vec<UCoord2D>> {
+        let dim = self.max - self.min + UCoord2D::new(1, 1);
+        let outside = UGrid2D::generate(dim, |coord| Ok(!self.contains(self.min + coord)))
+            .expect("Bounding box is within the grid");
+
+        let mut visited = vec![false; outside.data.len()];
+        let mut holes = Vec::new();
+
+        for (start, &is_outside) in outside.iter() {
+            if !is_outside || visited[start.x() + dim.x() * start.y()] {
+                continue;
+            }
+
+            visited[start.x() + dim.x() * start.y()] = true;
+            let mut group = vec![start];
+            let mut next = 0;
+            while let Some(&coord) = group.get(next) {
+                next += 1;
+                for (neighbor, &neighbor_outside) in outside.neighbors4(coord) {
+                    let idx = neighbor.x() + dim.x() * neighbor.y();
+                    if neighbor_outside && !visited[idx] {
+                        visited[idx] = true;
+                        group.push(neighbor);
+                    }
+                }
+            }
+
+            let on_border = |c: &UCoord2D| {
+                c.x() == 0 || c.y() == 0 || c.x() + 1 == dim.x() || c.y() + 1 == dim.y()
+            };
+            if !group.iter().any(on_border) {
+                let mut hole = group.into_iter().map(|c| c + self.min).collect::<Vec<_>>();
+                hole.sort_unstable_by_key(|c| (c.y(), c.x()));
+                holes.push(hole);
+            }
+        }
+
+        holes
+    }
+}
+
+impl UGrid2D<char> {
+    // Draws `c` on top of the cells at `coords`, skipping those outside of the grid. Overlays
+    // can be chained, later ones covering earlier ones.
+    pub fn overlay<C: TryAsUCoord<2>>(
+        mut self,
+        coords: impl IntoIterator<Item = C>,
+        c: char,
+    ) -> Self {
+        for coord in coords {
+            if let Ok(cell) = self.get_mut(coord) {
+                *cell = c;
+            }
+        }
+        self
+    }
+}
+
+impl<T: CellChar> Display for UGrid2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &str) -> UGrid2D<char> {
+        UGrid2D::parse(rows, |c, _| Ok(c)).unwrap()
+    }
+
+    #[test]
+    fn distances_and_regions() {
+        let maze = grid("..#.\n#.#.\n....\n.##A");
+        let open = |_: &char, to: &char| *to != '#';
+
+        let dist = maze.bfs_distances(UCoord2D::new(0, 0), open).unwrap();
+        assert_eq!(*dist.get(UCoord2D::new(3, 0)).unwrap(), Some(7));
+        assert_eq!(*dist.get(UCoord2D::new(0, 3)).unwrap(), Some(5));
+        assert_eq!(*dist.get(UCoord2D::new(2, 0)).unwrap(), None);
+        assert!(maze.bfs_distances(UCoord2D::new(4, 0), open).is_err());
+
+        let filled = maze.flood_fill(UCoord2D::new(0, 0), |a, b| a == b).unwrap();
+        assert_eq!(filled.len(), 10);
+        assert_eq!(filled[0], UCoord2D::new(0, 0));
+
+        let regions = maze.connected_components();
+        let ids = regions.iter().map(|(_, &id)| id).collect::<Vec<_>>();
+        assert_eq!(ids, [0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 3, 3, 4]);
+    }
+
+    #[test]
+    fn region_geometry() {
+        let garden = grid("OOOOO\nOXOXO\nOOOOO\nOXXOO\nOXXOO");
+        let regions = garden.regions();
+        assert_eq!(regions.len(), 4);
+
+        let o = &regions[0];
+        assert_eq!(o.area(), 19);
+        assert_eq!(o.perimeter(), 18 + 6 + 4 + 4);
+        assert_eq!(o.sides(), 8 + 4 + 4);
+        assert_eq!(o.bounding_box(), (UCoord2D::new(0, 0), UCoord2D::new(4, 4)));
+        assert_eq!(
+            o.holes(),
+            [vec![UCoord2D::new(1, 1)], vec![UCoord2D::new(3, 1)]]
+        );
+
+        let block = &regions[3];
+        assert_eq!(block.cells()[0], UCoord2D::new(1, 3));
+        assert_eq!((block.area(), block.perimeter(), block.sides()), (4, 8, 4));
+        assert!(block.holes().is_empty());
+    }
+
+    #[test]
+    fn holes_are_closed_by_diagonal_gaps() {
+        let regions = grid("###\n#.#\n##.").regions();
+        assert_eq!(regions[0].holes(), [vec![UCoord2D::new(1, 1)]]);
+
+        // The two enclosed cells only touch at a corner.
+        let regions = grid("####\n#.##\n##.#\n####").regions();
+        assert_eq!(
+            regions[0].holes(),
+            [vec![UCoord2D::new(1, 1)], vec![UCoord2D::new(2, 2)]]
+        );
+    }
+
+    #[test]
+    fn rotations_are_as_seen_on_screen() {
+        let abc = grid("abc\ndef");
+
+        assert_eq!(abc.rotate_left(), grid("cf\nbe\nad"));
+        assert_eq!(abc.rotate_right(), grid("da\neb\nfc"));
+        assert_eq!(abc.rotate_180(), grid("fed\ncba"));
+        assert_eq!(abc.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(abc.anti_transpose(), grid("fc\neb\nda"));
+        assert_eq!(abc.flip_horizontal_axis(), grid("def\nabc"));
+        assert_eq!(abc.flip_vertical_axis(), grid("cba\nfed"));
+    }
+
+    #[test]
+    fn rotations_of_non_square_grids() {
+        let wide = grid("abcd\nefgh");
+
+        let left = wide.rotate_left();
+        assert_eq!(left.dim(), UCoord2D::new(2, 4));
+        assert_eq!(left, grid("dh\ncg\nbf\nae"));
+        assert_eq!(wide.rotate_right(), grid("ea\nfb\ngc\nhd"));
+        assert_eq!(left.rotate_right(), wide);
+        assert_eq!(left.rotate_left().rotate_left().rotate_left(), wide);
+
+        let mut in_place = wide.clone();
+        in_place.rotate_left_in_place();
+        assert_eq!(in_place, left);
+        in_place.rotate_right_in_place();
+        in_place.rotate_right_in_place();
+        assert_eq!(in_place, wide.rotate_right());
+    }
+
+    #[test]
+    fn in_place_transforms_match() {
+        type Transform = (fn(&UGrid2D<char>) -> UGrid2D<char>, fn(&mut UGrid2D<char>));
+        let transforms: [Transform; 7] = [
+            (UGrid2D::rotate_left, UGrid2D::rotate_left_in_place),
+            (UGrid2D::rotate_right, UGrid2D::rotate_right_in_place),
+            (UGrid2D::rotate_180, UGrid2D::rotate_180_in_place),
+            (UGrid2D::transpose, UGrid2D::transpose_in_place),
+            (UGrid2D::anti_transpose, UGrid2D::anti_transpose_in_place),
+            (
+                UGrid2D::flip_horizontal_axis,
+                UGrid2D::flip_horizontal_axis_in_place,
+            ),
+            (
+                UGrid2D::flip_vertical_axis,
+                UGrid2D::flip_vertical_axis_in_place,
+            ),
+        ];
+
+        for rows in [
+            "abc\ndef",
+            "abcd\nefgh\nijkl",
+            "abc\ndef\nghi",
+            "a\nb\nc\nd\ne",
+        ] {
+            let original = grid(rows);
+            for (copying, in_place) in transforms {
+                let mut transformed = original.clone();
+                in_place(&mut transformed);
+                assert_eq!(transformed, copying(&original), "{rows:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn symmetries_are_distinct_for_asymmetric_shapes() {
+        let shape = grid("##.\n#..\n#..");
+        let symmetries = shape.symmetries();
+
+        for (idx, a) in symmetries.iter().enumerate() {
+            assert!(symmetries[idx + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    fn parse_error(input: &str) -> (UCoord2D, Errors) {
+        match UGrid2D::parse(input, |c, _| Ok(c)) {
+            Err(Errors::GridParseError(coord, error)) => (coord, *error),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_ragged_lines() {
+        let (coord, error) = parse_error("ab\nabc\nab");
+        assert_eq!(coord, UCoord2D::new(2, 1));
+        assert!(matches!(error, Errors::DimError(_)));
+
+        let (coord, error) = parse_error("abc\nab\nabc");
+        assert_eq!(coord, UCoord2D::new(2, 1));
+        assert!(matches!(error, Errors::DimError(_)));
+
+        let (coord, _) = parse_error("ab\n\nab");
+        assert_eq!(coord, UCoord2D::new(0, 1));
+    }
+
+    #[test]
+    fn parse_handles_crlf() {
+        let parsed = grid("ab\r\ncd\r\n");
+        assert_eq!(parsed.dim(), UCoord2D::new(2, 2));
+        assert_eq!(parsed, grid("ab\ncd"));
+    }
+
+    #[test]
+    fn parse_errors_carry_their_coordinate() {
+        let digits = UGrid2D::parse("12\n3x", |c, _| c.to_digit(10).ok_or(Errors::ParseError));
+        match digits {
+            Err(Errors::GridParseError(coord, error)) => {
+                assert_eq!(coord, UCoord2D::new(1, 1));
+                assert!(matches!(*error, Errors::ParseError));
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_with_markers_finds_each_marker_once() {
+        let (maze, found) =
+            UGrid2D::parse_with_markers("S.#\n.#E", &['S', 'E'], |c, _| Ok(c == '#')).unwrap();
+        assert_eq!(found[&'S'], UCoord2D::new(0, 0));
+        assert_eq!(found[&'E'], UCoord2D::new(2, 1));
+        assert!(!*maze.get(UCoord2D::new(2, 1)).unwrap());
+
+        let twice = UGrid2D::parse_with_markers("S.\n.S", &['S'], |c, _| Ok(c));
+        match twice {
+            Err(Errors::GridParseError(coord, error)) => {
+                assert_eq!(coord, UCoord2D::new(1, 1));
+                assert!(matches!(*error, Errors::UncategorizedError(_)));
+            }
+            other => panic!("expected a duplicate marker, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn wrapping_access() {
+        let abc = grid("abc\ndef");
+
+        assert_eq!(
+            abc.wrap(ICoord2D::new(-1, -1)).unwrap(),
+            UCoord2D::new(2, 1)
+        );
+        assert_eq!(abc.wrap(ICoord2D::new(7, 4)).unwrap(), UCoord2D::new(1, 0));
+        assert_eq!(abc.wrap(UCoord2D::new(3, 2)).unwrap(), UCoord2D::new(0, 0));
+        assert_eq!(*abc.get_wrapping(ICoord2D::new(-4, 3)).unwrap(), 'f');
+        assert_eq!(
+            abc.step_wrapping(UCoord2D::new(0, 0), ICoord2D::new(-1, 0))
+                .unwrap(),
+            UCoord2D::new(2, 0)
+        );
+        assert_eq!(
+            abc.step_wrapping(UCoord2D::new(2, 1), ICoord2D::new(2, 5))
+                .unwrap(),
+            UCoord2D::new(1, 0)
+        );
+
+        let mut abc = abc;
+        *abc.get_wrapping_mut(ICoord2D::new(-3, -2)).unwrap() = 'A';
+        assert_eq!(abc, grid("Abc\ndef"));
+
+        let empty = grid("");
+        assert!(matches!(
+            empty.wrap(ICoord2D::new(0, 0)),
+            Err(Errors::DimError(_))
+        ));
+        assert!(empty.get_wrapping(ICoord2D::new(1, 1)).is_err());
+    }
+
+    #[test]
+    fn render_and_overlay() {
+        let heights = UGrid2D::parse("012\n345", |c, _| Ok(c.to_digit(10).unwrap())).unwrap();
+
+        let rendered = heights.render_with(|&h, _| if h % 2 == 0 { '#' } else { '.' });
+        assert_eq!(rendered.to_string(), "#.#\n.#.");
+
+        // Later overlays cover earlier ones, coordinates outside of the grid are skipped.
+        let drawn = rendered
+            .overlay([UCoord2D::new(0, 0), UCoord2D::new(1, 1)], 'a')
+            .overlay(
+                [
+                    ICoord2D::new(1, 1),
+                    ICoord2D::new(-1, 0),
+                    ICoord2D::new(3, 0),
+                ],
+                'b',
+            )
+            .overlay([UCoord2D::new(2, 5)], 'c');
+        assert_eq!(drawn.to_string(), "a.#\n.b.");
+
+        assert_eq!(grid("ab\ncd").to_string(), "ab\ncd");
+        assert_eq!(grid("").to_string(), "");
+    }
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let abc = grid("abc\ndef\nghi");
+        let cells = |it: &mut dyn Iterator<Item = (UCoord2D, &char)>| {
+            it.map(|(_, &c)| c).collect::<String>()
+        };
+
+        assert_eq!(cells(&mut abc.neighbors4(UCoord2D::new(1, 1))), "bfhd");
+        assert_eq!(cells(&mut abc.neighbors8(UCoord2D::new(1, 1))), "bcfihgda");
+        assert_eq!(cells(&mut abc.neighbors4(UCoord2D::new(0, 0))), "bd");
+        assert_eq!(cells(&mut abc.neighbors8(UCoord2D::new(0, 0))), "bed");
+        assert_eq!(cells(&mut abc.neighbors4(UCoord2D::new(2, 1))), "cie");
+        assert_eq!(cells(&mut abc.neighbors8(UCoord2D::new(2, 2))), "fhe");
+        assert_eq!(
+            abc.neighbors4(UCoord2D::new(0, 2)).next(),
+            Some((UCoord2D::new(0, 1), &'d'))
+        );
+    }
+
+    #[test]
+    fn rows_cols_and_find() {
+        let mut abc = grid("abc\ndef");
+
+        let rows = abc.rows().map(|row| row.iter().collect::<String>());
+        assert_eq!(rows.collect::<Vec<_>>(), ["abc", "def"]);
+        let cols = abc.cols().map(|col| col.collect::<String>());
+        assert_eq!(cols.collect::<Vec<_>>(), ["ad", "be", "cf"]);
+
+        assert_eq!(abc.find(|&c| c == 'e'), Some(UCoord2D::new(1, 1)));
+        assert_eq!(abc.find(|&c| c > 'a'), Some(UCoord2D::new(1, 0)));
+        assert_eq!(abc.find(|&c| c == 'z'), None);
+
+        for (coord, cell) in abc.iter_mut() {
+            if coord == UCoord2D::new(2, 0) || coord == UCoord2D::new(0, 1) {
+                *cell = cell.to_ascii_uppercase();
+            }
+        }
+        assert_eq!(abc, grid("abC\nDef"));
+        assert_eq!(
+            abc.iter().map(|(coord, _)| coord).last(),
+            Some(UCoord2D::new(2, 1))
+        );
+    }
 }
@@ -0,0 +1,215 @@
+pub use crate::coord::TryAsUCoord;
+pub use crate::coord::UCoord3D;
+use crate::coord::{Coord, ICoord3D, UCoord2D};
+pub use crate::error::Errors;
+use crate::grid2d::UGrid2D;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UGrid3D<T> {
+    dim: UCoord3D,
+    data: Vec<T>,
+}
+
+impl<T: Default + Clone> UGrid3D<T> {
+    #[inline(always)]
+    pub fn from_default(dim: UCoord3D) -> Self {
+        Self {
+            dim,
+            data: vec![T::default(); dim.x() * dim.y() * dim.z()],
+        }
+    }
+}
+
+impl<T: Clone> UGrid3D<T> {
+    #[inline(always)]
+    fn internal_index(&self, coord: impl TryAsUCoord<3>) -> Result<usize, Errors> {
+        let coord = coord.try_as_ucoord()?;
+        match coord {
+            Coord([x, _, _]) if x >= self.dim.x() => Err(Errors::DimError(format!(
+                "x ({}) equal or above dimensional bound ({})",
+                x,
+                self.dim.x()
+            ))),
+            Coord([_, y, _]) if y >= self.dim.y() => Err(Errors::DimError(format!(
+                "y ({}) equal or above dimensional bound ({})",
+                y,
+                self.dim.y()
+            ))),
+            Coord([_, _, z]) if z >= self.dim.z() => Err(Errors::DimError(format!(
+                "z ({}) equal or above dimensional bound ({})",
+                z,
+                self.dim.z()
+            ))),
+            Coord([x, y, z]) => Ok(x + self.dim.x() * (y + self.dim.y() * z)),
+        }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, coord: impl TryAsUCoord<3>) -> Result<&T, Errors> {
+        let idx = self.internal_index(coord)?;
+        self.data
+            .get(idx)
+            .ok_or(Errors::DimError("Unexpected dim error".into()))
+    }
+
+    #[inline(always)]
+    pub fn get_mut(&mut self, coord: impl TryAsUCoord<3>) -> Result<&mut T, Errors> {
+        let idx = self.internal_index(coord)?;
+        self.data
+            .get_mut(idx)
+            .ok_or(Errors::DimError("Unexpected dim error".into()))
+    }
+
+    #[inline(always)]
+    pub fn generate<F: FnMut(UCoord3D) -> Result<T, Errors>>(
+        dim: UCoord3D,
+        mut f: F,
+    ) -> Result<Self, Errors> {
+        let mut data: Vec<T> = Vec::with_capacity(dim.x() * dim.y() * dim.z());
+        for iz in 0..dim.z() {
+            for iy in 0..dim.y() {
+                for ix in 0..dim.x() {
+                    let coord = UCoord3D::new(ix, iy, iz);
+                    data.push(f(coord)?)
+                }
+            }
+        }
+        Ok(Self { dim, data })
+    }
+
+    #[inline(always)]
+    pub fn sub_grid(&self, start: UCoord3D, dim: UCoord3D) -> Result<Self, Errors> {
+        Self::generate(dim, |new_coord| Ok(self.get(start + new_coord)?.clone()))
+    }
+
+    // The plane at `index` across `axis`. Its x and y follow the remaining axes in order, so
+    // a slice across X is indexed by (y, z), across Y by (x, z) and across Z by (x, y).
+    pub fn slice(&self, axis: Axis, index: usize) -> Result<UGrid2D<T>, Errors> {
+        let Coord([x, y, z]) = self.dim;
+        let (dim, to_3d): (UCoord2D, fn(UCoord2D, usize) -> UCoord3D) = match axis {
+            Axis::X => (UCoord2D::new(y, z), |c, i| UCoord3D::new(i, c.x(), c.y())),
+            Axis::Y => (UCoord2D::new(x, z), |c, i| UCoord3D::new(c.x(), i, c.y())),
+            Axis::Z => (UCoord2D::new(x, y), |c, i| UCoord3D::new(c.x(), c.y(), i)),
+        };
+
+        UGrid2D::generate(dim, |coord| Ok(self.get(to_3d(coord, index))?.clone()))
+    }
+}
+
+impl<T> UGrid3D<T> {
+    #[inline(always)]
+    pub fn dim(&self) -> UCoord3D {
+        self.dim
+    }
+
+    #[inline(always)]
+    pub fn coord_to_grid(&self, coord: impl TryInto<UCoord3D>) -> Option<UCoord3D> {
+        let candidate: Option<UCoord3D> = coord.try_into().ok();
+        match candidate {
+            Some(Coord([x, _, _])) if x >= self.dim.x() => None,
+            Some(Coord([_, y, _])) if y >= self.dim.y() => None,
+            Some(Coord([_, _, z])) if z >= self.dim.z() => None,
+            val => val,
+        }
+    }
+
+    // Cells ordered by z, then y, then x, together with their coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = (UCoord3D, &T)> {
+        let Coord([w, h, _]) = self.dim;
+        self.data.iter().enumerate().map(move |(idx, cell)| {
+            let coord = UCoord3D::new(idx % w, idx / w % h, idx / (w * h));
+            (coord, cell)
+        })
+    }
+
+    fn neighbors(
+        &self,
+        coord: UCoord3D,
+        offsets: impl Iterator<Item = ICoord3D>,
+    ) -> impl Iterator<Item = (UCoord3D, &T)> {
+        offsets.filter_map(move |offset| {
+            let n = self.coord_to_grid(coord + offset)?;
+            Some((
+                n,
+                &self.data[n.x() + self.dim.x() * (n.y() + self.dim.y() * n.z())],
+            ))
+        })
+    }
+
+    // The in-bounds cells sharing a face with `coord`.
+    pub fn neighbors6(&self, coord: UCoord3D) -> impl Iterator<Item = (UCoord3D, &T)> {
+        const OFFSETS: [ICoord3D; 6] = [
+            ICoord3D::new(-1, 0, 0),
+            ICoord3D::new(1, 0, 0),
+            ICoord3D::new(0, -1, 0),
+            ICoord3D::new(0, 1, 0),
+            ICoord3D::new(0, 0, -1),
+            ICoord3D::new(0, 0, 1),
+        ];
+        self.neighbors(coord, OFFSETS.into_iter())
+    }
+
+    // The in-bounds cells sharing a face, an edge or a corner with `coord`.
+    pub fn neighbors26(&self, coord: UCoord3D) -> impl Iterator<Item = (UCoord3D, &T)> {
+        let offsets = (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| ICoord3D::new(x, y, z))))
+            .filter(|&offset| offset != ICoord3D::default());
+        self.neighbors(coord, offsets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube() -> UGrid3D<UCoord3D> {
+        UGrid3D::generate(UCoord3D::new(2, 3, 4), Ok).unwrap()
+    }
+
+    #[test]
+    fn slices_follow_the_remaining_axes() {
+        let grid = cube();
+
+        let across_x = grid.slice(Axis::X, 1).unwrap();
+        assert_eq!(across_x.dim(), UCoord2D::new(3, 4));
+        assert_eq!(
+            *across_x.get(UCoord2D::new(2, 3)).unwrap(),
+            UCoord3D::new(1, 2, 3)
+        );
+
+        let across_y = grid.slice(Axis::Y, 2).unwrap();
+        assert_eq!(across_y.dim(), UCoord2D::new(2, 4));
+        assert_eq!(
+            *across_y.get(UCoord2D::new(1, 3)).unwrap(),
+            UCoord3D::new(1, 2, 3)
+        );
+
+        let across_z = grid.slice(Axis::Z, 3).unwrap();
+        assert_eq!(across_z.dim(), UCoord2D::new(2, 3));
+        assert_eq!(
+            *across_z.get(UCoord2D::new(1, 2)).unwrap(),
+            UCoord3D::new(1, 2, 3)
+        );
+
+        assert!(grid.slice(Axis::Z, 4).is_err());
+    }
+
+    #[test]
+    fn neighborhoods_stay_in_bounds() {
+        let grid = UGrid3D::<u8>::from_default(UCoord3D::new(3, 3, 3));
+        let center = UCoord3D::new(1, 1, 1);
+
+        assert_eq!(grid.neighbors6(center).count(), 6);
+        assert_eq!(grid.neighbors26(center).count(), 26);
+        assert_eq!(grid.neighbors6(UCoord3D::default()).count(), 3);
+        assert_eq!(grid.neighbors26(UCoord3D::default()).count(), 7);
+        assert!(grid.iter().all(|(coord, _)| grid.get(coord).is_ok()));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::Errors;
+use crate::inputs::{cache_path, default_cache_dir};
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+    Lookup { year: u16, day: u8 },
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Errors> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Path(path) => read_first(std::slice::from_ref(path)),
+            InputSource::Lookup { year, day } => read_first(&candidates(*year, *day)),
+        }
+    }
+}
+
+// Days are zero padded, so the input for 2024 day 1 is looked up as `<dir>/2024/01.txt`,
+// `inputs/2024/01` and then in the per-user cache. Nothing is downloaded here, see `inputs`.
+pub fn candidates(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut paths = Vec::with_capacity(3);
+
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        paths.push(
+            Path::new(&dir)
+                .join(year.to_string())
+                .join(format!("{day:02}.txt")),
+        );
+    }
+
+    paths.push(
+        Path::new(DEFAULT_INPUT_DIR)
+            .join(year.to_string())
+            .join(format!("{day:02}")),
+    );
+
+    if let Some(dir) = default_cache_dir() {
+        paths.push(cache_path(&dir, year, day));
+    }
+
+    paths
+}
+
+pub fn answers_candidates() -> Vec<PathBuf> {
+    let mut paths = Vec::with_capacity(2);
+
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        paths.push(Path::new(&dir).join(ANSWERS_FILE));
+    }
+
+    paths.push(Path::new(DEFAULT_INPUT_DIR).join(ANSWERS_FILE));
+
+    paths
+}
+
+pub fn read_answers() -> Result<String, Errors> {
+    read_first(&answers_candidates())
+}
+
+fn read_first(paths: &[PathBuf]) -> Result<String, Errors> {
+    for path in paths {
+        match std::fs::read_to_string(path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() == ErrorKind::NotFound => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    Err(Errors::InputNotFound(paths.to_vec()))
+}
@@ -0,0 +1,313 @@
+use crate::error::Errors;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+#[cfg(feature = "fetch")]
+const USER_AGENT: &str = "aoc-runner (puzzle inputs are cached and fetched at most once)";
+
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Errors>;
+}
+
+// Copies inputs out of a directory laid out like the cache, e.g. one shared between machines.
+#[derive(Clone, Debug)]
+pub struct FsFetcher {
+    root: PathBuf,
+}
+
+impl FsFetcher {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Fetcher for FsFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Errors> {
+        let path = cache_path(&self.root, year, day);
+
+        std::fs::read_to_string(&path).map_err(|error| match error.kind() {
+            ErrorKind::NotFound => Errors::InputNotFound(vec![path]),
+            _ => error.into(),
+        })
+    }
+}
+
+// Downloads inputs from the puzzle site, authenticated with the session cookie of the user.
+#[cfg(feature = "fetch")]
+#[derive(Clone, Debug)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: Option<String>,
+}
+
+#[cfg(feature = "fetch")]
+impl HttpFetcher {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(BASE_URL, std::env::var(SESSION_VAR).ok())
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Errors> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            Errors::FetchError(format!("set {SESSION_VAR} to download {year} day {day:02}"))
+        })?;
+        let url = format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        );
+        let fetch_error = |error: ureq::Error| Errors::FetchError(format!("{url}: {error}"));
+
+        ureq::get(&url)
+            .header("Cookie", format!("session={}", session.trim()))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(fetch_error)?
+            .body_mut()
+            .read_to_string()
+            .map_err(fetch_error)
+    }
+}
+
+// Puzzles unlock at midnight EST (UTC-5) on their day of December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // Days from 1970-01-01 to December 1st, counting years from March so that leap days come
+    // last; December is then month 9 and starts on day 275 of that year.
+    let year = u64::from(year);
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = 275 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
+
+// `$AOC_CACHE_DIR`, otherwise `aoc` in the user's cache directory.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+        return Some(dir.into());
+    }
+
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .map(|dir| dir.join("aoc"))
+}
+
+pub fn cache_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("{day:02}.txt"))
+}
+
+#[derive(Clone, Debug)]
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+}
+
+#[cfg(feature = "fetch")]
+impl InputCache<HttpFetcher> {
+    pub fn from_env() -> Option<Self> {
+        default_cache_dir().map(|dir| Self::new(dir, HttpFetcher::from_env()))
+    }
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        cache_path(&self.dir, year, day)
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<String, Errors> {
+        self.get_at(year, day, SystemTime::now())
+    }
+
+    // Cached inputs are returned as they are, the fetcher is only asked for missing ones.
+    pub fn get_at(&self, year: u16, day: u8, now: SystemTime) -> Result<String, Errors> {
+        let path = self.path(year, day);
+
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+
+        if now < unlock_time(year, day) {
+            return Err(Errors::NotUnlocked(year, day));
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+
+        // Written next to the final path and renamed, so an interrupted run never leaves a
+        // truncated input behind that would be trusted from then on.
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let partial = path.with_extension("txt.part");
+        std::fs::write(&partial, &input)?;
+        std::fs::rename(&partial, &path)?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingFetcher {
+        calls: Cell<usize>,
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, Errors> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("{year} {day}\n"))
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let secs = |year, day| {
+            unlock_time(year, day)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+
+        assert_eq!(secs(2015, 1), 1_448_946_000);
+        assert_eq!(secs(2024, 25), 1_735_102_800);
+    }
+
+    #[test]
+    fn fetches_once_and_serves_from_cache() {
+        let dir = scratch_dir("cache");
+        let cache = InputCache::new(
+            &dir,
+            CountingFetcher {
+                calls: Cell::new(0),
+            },
+        );
+
+        assert_eq!(cache.get(2024, 1).unwrap(), "2024 1\n");
+        assert_eq!(cache.get(2024, 1).unwrap(), "2024 1\n");
+        assert_eq!(cache.fetcher.calls.get(), 1);
+        assert!(cache.path(2024, 1).ends_with("2024/01.txt"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_locked_puzzles() {
+        let dir = scratch_dir("locked");
+        let cache = InputCache::new(
+            &dir,
+            CountingFetcher {
+                calls: Cell::new(0),
+            },
+        );
+        let before = unlock_time(2024, 5) - Duration::from_secs(1);
+
+        assert!(matches!(
+            cache.get_at(2024, 5, before),
+            Err(Errors::NotUnlocked(2024, 5))
+        ));
+        assert_eq!(cache.fetcher.calls.get(), 0);
+        assert!(cache.get_at(2024, 5, unlock_time(2024, 5)).is_ok());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fs_fetcher_reads_cache_layout() {
+        let dir = scratch_dir("fs");
+        std::fs::create_dir_all(dir.join("2015")).unwrap();
+        std::fs::write(dir.join("2015/03.txt"), "^>v<\n").unwrap();
+        let fetcher = FsFetcher::new(&dir);
+
+        assert_eq!(fetcher.fetch(2015, 3).unwrap(), "^>v<\n");
+        assert!(matches!(
+            fetcher.fetch(2015, 4),
+            Err(Errors::InputNotFound(_))
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    mod http {
+        use super::*;
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        // Serves a single request with the given status and body, handing back the request head.
+        fn mock_server(status: &str, body: &str) -> (String, std::thread::JoinHandle<String>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+
+            let handle = std::thread::spawn(move || {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                while reader.read_line(&mut request).unwrap() > 2 {}
+                stream.write_all(response.as_bytes()).unwrap();
+                request
+            });
+
+            (url, handle)
+        }
+
+        #[test]
+        fn http_fetcher_sends_session() {
+            let (url, server) = mock_server("200 OK", "1 2\n3 4\n");
+            let fetcher = HttpFetcher::new(url, Some("cafe".into()));
+
+            assert_eq!(fetcher.fetch(2024, 1).unwrap(), "1 2\n3 4\n");
+
+            let request = server.join().unwrap();
+            assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+            assert!(request.to_lowercase().contains("cookie: session=cafe\r\n"));
+        }
+
+        #[test]
+        fn http_fetcher_reports_errors() {
+            let (url, server) = mock_server("404 Not Found", "");
+            let fetcher = HttpFetcher::new(url, Some("cafe".into()));
+
+            assert!(matches!(fetcher.fetch(2024, 1), Err(Errors::FetchError(_))));
+            server.join().unwrap();
+
+            let fetcher = HttpFetcher::new(BASE_URL, None);
+            assert!(matches!(fetcher.fetch(2024, 1), Err(Errors::FetchError(_))));
+        }
+    }
+}
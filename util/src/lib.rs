@@ -1,6 +1,13 @@
-pub mod coord2d;
-pub mod coord3d;
+pub mod coord;
 pub mod direction;
 pub mod direction8;
+pub mod dsu;
 pub mod error;
 pub mod grid2d;
+pub mod grid3d;
+pub mod input;
+pub mod inputs;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod testing;
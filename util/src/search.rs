@@ -0,0 +1,419 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+// The outcome of a search: the best known cost of every state reached, and the states each
+// one was reached from. States settled before the search stopped have their final cost; the
+// ones still waiting in the queue only have an upper bound.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    start: S,
+    goals: Vec<S>,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy + Ord> Search<S, C> {
+    #[inline(always)]
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    // The first goal state settled, if the search reached any.
+    #[inline(always)]
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    // The goal states reached at the lowest cost. Only the all-paths searches look for more
+    // than the first one.
+    #[inline(always)]
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    #[inline(always)]
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal()?)
+    }
+
+    #[inline(always)]
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    #[inline(always)]
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    #[inline(always)]
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.predecessors(state).first()
+    }
+
+    // Every state `state` is reached from at its best cost when all paths were recorded,
+    // otherwise just the one it was first reached from.
+    #[inline(always)]
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    // The states from the start up to and including `state`, following the predecessors.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.predecessor(path.last()?) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    #[inline(always)]
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal()?)
+    }
+
+    // The states on any cheapest path from the start to one of the goals, goals included.
+    pub fn all_optimal_paths_states(&self) -> HashSet<S> {
+        let mut states = self.goals.iter().cloned().collect::<HashSet<_>>();
+        let mut queue = self.goals.clone();
+
+        while let Some(state) = queue.pop() {
+            for prev in self.predecessors(&state) {
+                if states.insert(prev.clone()) {
+                    queue.push(prev.clone());
+                }
+            }
+        }
+
+        states
+    }
+
+    // The number of distinct cheapest paths from the start to any of the goals.
+    pub fn count_optimal_paths(&self) -> usize {
+        // Counted from the goals back through the predecessors, so a state's count is known
+        // before the states reached from it, whatever the step costs. A cycle of zero-cost
+        // steps adds nothing on its way back around.
+        let mut counts: HashMap<&S, usize> = HashMap::from([(&self.start, 1)]);
+        let mut visited = HashSet::from([&self.start]);
+        let mut stack = self
+            .goals
+            .iter()
+            .map(|goal| (goal, false))
+            .collect::<Vec<_>>();
+
+        while let Some((state, expanded)) = stack.pop() {
+            if expanded {
+                let count = self
+                    .predecessors(state)
+                    .iter()
+                    .map(|prev| counts.get(prev).copied().unwrap_or(0))
+                    .sum();
+                counts.insert(state, count);
+            } else if visited.insert(state) {
+                stack.push((state, true));
+                stack.extend(
+                    self.predecessors(state)
+                        .iter()
+                        .filter(|prev| !visited.contains(prev))
+                        .map(|prev| (prev, false)),
+                );
+            }
+        }
+
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+}
+
+struct QueueEntry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for QueueEntry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for QueueEntry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for QueueEntry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for QueueEntry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).reverse()
+    }
+}
+
+// Cheapest-first search from `start` until a state satisfying `is_goal` is settled. With a
+// goal that never matches, it settles every reachable state.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, |_| C::default(), is_goal, false)
+}
+
+// Like `dijkstra`, with states explored in order of their cost plus `heuristic`. The goal is
+// reached at its lowest cost as long as the heuristic never overestimates the remaining cost
+// and does not drop by more than the cost of any single step.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, heuristic, is_goal, false)
+}
+
+// Like `dijkstra`, recording every predecessor that reaches a state at its best cost, and
+// every goal reached at the lowest cost. Step costs have to be positive.
+pub fn dijkstra_all_paths<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, |_| C::default(), is_goal, true)
+}
+
+// Like `astar`, recording all cheapest paths as `dijkstra_all_paths` does.
+pub fn astar_all_paths<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, heuristic, is_goal, true)
+}
+
+fn search<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all_paths: bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search {
+        start: start.clone(),
+        goals: Vec::new(),
+        distances: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::from([QueueEntry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut goal_priority = None;
+
+    while let Some(QueueEntry {
+        priority,
+        cost,
+        state,
+    }) = queue.pop()
+    {
+        // Anything popped after the first goal only matters for ties with it.
+        if goal_priority.is_some_and(|goal_priority| priority > goal_priority) {
+            break;
+        }
+        if search
+            .distances
+            .get(&state)
+            .is_some_and(|&best| best < cost)
+        {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goals.push(state);
+            goal_priority = Some(priority);
+            if all_paths {
+                continue;
+            }
+            break;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match search.distances.get(&next) {
+                Some(&best) if best < next_cost => continue,
+                Some(&best) if best == next_cost => {
+                    if all_paths {
+                        search
+                            .predecessors
+                            .entry(next)
+                            .or_default()
+                            .push(state.clone());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            search.distances.insert(next.clone(), next_cost);
+            search
+                .predecessors
+                .insert(next.clone(), vec![state.clone()]);
+            queue.push(QueueEntry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::ICoord2D;
+    use crate::direction::Direction;
+
+    // A 5x5 open area with a wall from (2, 0) to (2, 3), so paths go around at y = 4.
+    fn open(coord: ICoord2D) -> bool {
+        (0..5).contains(&coord.x())
+            && (0..5).contains(&coord.y())
+            && !(coord.x() == 2 && coord.y() < 4)
+    }
+
+    fn successors(coord: &ICoord2D) -> Vec<(ICoord2D, usize)> {
+        Direction::directions()
+            .into_iter()
+            .map(|dir| *coord + dir.to_offset())
+            .filter(|&next| open(next))
+            .map(|next| (next, 1))
+            .collect()
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let goal = ICoord2D::new(4, 0);
+
+        let plain = dijkstra(ICoord2D::default(), successors, |&c| c == goal);
+        let guided = astar(
+            ICoord2D::default(),
+            successors,
+            |&c| c.manhattan(goal),
+            |&c| c == goal,
+        );
+
+        for search in [&plain, &guided] {
+            assert_eq!(search.goal(), Some(&goal));
+            assert_eq!(search.goal_distance(), Some(12));
+
+            let path = search.path().unwrap();
+            assert_eq!(path.len(), 13);
+            assert_eq!(path.first(), Some(&ICoord2D::default()));
+            assert!(
+                path.windows(2)
+                    .all(|w| w[0].manhattan(w[1]) == 1 && open(w[1]))
+            );
+        }
+        assert!(guided.distances().len() <= plain.distances().len());
+    }
+
+    #[test]
+    fn all_optimal_paths() {
+        // In a 3x3 open area, corner to corner has six shortest paths.
+        let successors = |coord: &ICoord2D| {
+            Direction::directions()
+                .into_iter()
+                .map(|dir| *coord + dir.to_offset())
+                .filter(|next| (0..3).contains(&next.x()) && (0..3).contains(&next.y()))
+                .map(|next| (next, 1usize))
+                .collect::<Vec<_>>()
+        };
+        let goal = ICoord2D::new(2, 2);
+
+        let search = dijkstra_all_paths(ICoord2D::default(), successors, |&c| c == goal);
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.count_optimal_paths(), 6);
+        assert_eq!(search.all_optimal_paths_states().len(), 9);
+        assert_eq!(search.predecessors(&goal).len(), 2);
+
+        let single = dijkstra(ICoord2D::default(), successors, |&c| c == goal);
+        assert_eq!(single.count_optimal_paths(), 1);
+        assert_eq!(single.all_optimal_paths_states().len(), 5);
+
+        // Both goals are at the same cost, each with a single path along the edge.
+        let corners = dijkstra_all_paths(ICoord2D::default(), successors, |&c| {
+            c == ICoord2D::new(2, 0) || c == ICoord2D::new(0, 2)
+        });
+        assert_eq!(corners.goals().len(), 2);
+        assert_eq!(corners.count_optimal_paths(), 2);
+    }
+
+    #[test]
+    fn exhaustive_without_goal() {
+        let search = dijkstra(ICoord2D::default(), successors, |_| false);
+
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distances().len(), 21);
+        assert_eq!(search.distance(&ICoord2D::new(2, 4)), Some(6));
+        assert_eq!(search.path_to(&ICoord2D::new(9, 9)), None);
+    }
+
+    #[test]
+    fn zero_cost_steps() {
+        let chain = dijkstra(
+            0u32,
+            |&s| if s < 3 { vec![(s + 1, 0usize)] } else { vec![] },
+            |&s| s == 3,
+        );
+        assert_eq!(chain.goal_distance(), Some(0));
+        assert_eq!(chain.count_optimal_paths(), 1);
+
+        // Two free ways from 0 to 3.
+        let diamond = dijkstra_all_paths(
+            0u32,
+            |&s| match s {
+                0 => vec![(1, 0usize), (2, 0)],
+                1 | 2 => vec![(3, 0)],
+                _ => vec![],
+            },
+            |&s| s == 3,
+        );
+        assert_eq!(diamond.count_optimal_paths(), 2);
+        assert_eq!(diamond.all_optimal_paths_states().len(), 4);
+    }
+}
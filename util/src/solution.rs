@@ -0,0 +1,74 @@
+use crate::error::Errors;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // The last day of a year has a single part; such days set this to `false` and use
+    // `NoAnswer` for the second answer.
+    const HAS_PART2: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Errors>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Errors>;
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2.unwrap_or_default()
+    }
+}
+
+pub fn solve<S: Solution>(input: &str) -> Result<(Answers, Timings), Errors> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&input)?.to_string();
+    let part1_time = start.elapsed();
+
+    let (part2, part2_time) = if S::HAS_PART2 {
+        let start = Instant::now();
+        let part2 = S::part2(&input)?.to_string();
+        (Some(part2), Some(start.elapsed()))
+    } else {
+        (None, None)
+    };
+
+    Ok((
+        Answers { part1, part2 },
+        Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    ))
+}
@@ -0,0 +1,286 @@
+use crate::coord::{ICoord2D, TryAsICoord, TryAsUCoord, UCoord2D};
+use crate::direction::Direction;
+use crate::direction8::Direction8;
+use crate::error::Errors;
+use crate::grid2d::{CellChar, UGrid2D};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// A grid without fixed dimensions, holding only the cells that were set. The bounding box
+// grows with every cell inserted or coordinate included, and does not shrink on removal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid2D<T> {
+    cells: HashMap<ICoord2D, T>,
+    bounds: Option<(ICoord2D, ICoord2D)>,
+}
+
+fn dense_dim(min: ICoord2D, max: ICoord2D) -> Result<UCoord2D, Errors> {
+    Ok((max - min).try_as_ucoord()? + UCoord2D::new(1, 1))
+}
+
+impl<T> Default for SparseGrid2D<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid2D<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Lines are rows as for `UGrid2D::parse`, with `f` deciding which characters become cells.
+    // The bounding box spans the whole text, including the characters left empty.
+    pub fn parse<F: FnMut(char, ICoord2D) -> Result<Option<T>, Errors>>(
+        input: &str,
+        mut f: F,
+    ) -> Result<Self, Errors> {
+        let mut grid = Self::new();
+
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let coord = UCoord2D::new(x, y).try_as_icoord()?;
+                grid.include(coord);
+                if let Some(value) = f(c, coord)? {
+                    grid.insert(coord, value);
+                }
+            }
+        }
+
+        Ok(grid)
+    }
+
+    // Grows the bounding box to contain `coord` without setting a cell there.
+    pub fn include(&mut self, coord: ICoord2D) {
+        self.bounds = Some(match self.bounds {
+            None => (coord, coord),
+            Some((min, max)) => (min.component_min(coord), max.component_max(coord)),
+        });
+    }
+
+    // The smallest and largest corner, both inclusive.
+    #[inline(always)]
+    pub fn bounds(&self) -> Option<(ICoord2D, ICoord2D)> {
+        self.bounds
+    }
+
+    #[inline(always)]
+    pub fn in_bounds(&self, coord: ICoord2D) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x()..=max.x()).contains(&coord.x()) && (min.y()..=max.y()).contains(&coord.y())
+        })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, coord: impl TryAsICoord<2>) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline(always)]
+    pub fn get(&self, coord: impl TryAsICoord<2>) -> Option<&T> {
+        self.cells.get(&coord.try_as_icoord().ok()?)
+    }
+
+    #[inline(always)]
+    pub fn get_mut(&mut self, coord: impl TryAsICoord<2>) -> Option<&mut T> {
+        self.cells.get_mut(&coord.try_as_icoord().ok()?)
+    }
+
+    pub fn get_or_insert_with(&mut self, coord: ICoord2D, f: impl FnOnce() -> T) -> &mut T {
+        self.include(coord);
+        self.cells.entry(coord).or_insert_with(f)
+    }
+
+    pub fn insert(&mut self, coord: ICoord2D, value: T) -> Option<T> {
+        self.include(coord);
+        self.cells.insert(coord, value)
+    }
+
+    pub fn remove(&mut self, coord: ICoord2D) -> Option<T> {
+        self.cells.remove(&coord)
+    }
+
+    // Set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (ICoord2D, &T)> {
+        self.cells.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (ICoord2D, &mut T)> {
+        self.cells.iter_mut().map(|(&coord, value)| (coord, value))
+    }
+
+    // The set orthogonal neighbors of `coord`, in `Direction` order.
+    pub fn neighbors4(&self, coord: ICoord2D) -> impl Iterator<Item = (ICoord2D, &T)> {
+        Direction::directions().into_iter().filter_map(move |dir| {
+            let neighbor = coord + dir.to_offset();
+            Some((neighbor, self.cells.get(&neighbor)?))
+        })
+    }
+
+    // Like `neighbors4`, including the diagonals, in `Direction8` order.
+    pub fn neighbors8(&self, coord: ICoord2D) -> impl Iterator<Item = (ICoord2D, &T)> {
+        Direction8::directions().into_iter().filter_map(move |dir| {
+            let neighbor = coord + dir.to_offset();
+            Some((neighbor, self.cells.get(&neighbor)?))
+        })
+    }
+
+    // Draws the bounding box with `f`, which gets `None` for cells that are not set. The
+    // result is a dense grid, so it displays and takes overlays like any other rendering.
+    pub fn render_with<F: FnMut(Option<&T>, ICoord2D) -> char>(
+        &self,
+        mut f: F,
+    ) -> Result<UGrid2D<char>, Errors> {
+        let Some((origin, max)) = self.bounds else {
+            return UGrid2D::generate(UCoord2D::default(), |_| Ok(' '));
+        };
+
+        UGrid2D::generate(dense_dim(origin, max)?, |coord| {
+            let coord = coord + origin;
+            Ok(f(self.cells.get(&coord), coord))
+        })
+    }
+}
+
+impl<T: Clone> SparseGrid2D<T> {
+    // The bounding box as a dense grid with `fill` in the cells that are not set, along with
+    // the origin, the coordinate of the dense grid's top left cell.
+    pub fn to_dense(&self, fill: T) -> Result<(UGrid2D<T>, ICoord2D), Errors> {
+        let Some((origin, max)) = self.bounds else {
+            return Ok((
+                UGrid2D::generate(UCoord2D::default(), |_| Ok(fill.clone()))?,
+                ICoord2D::default(),
+            ));
+        };
+
+        let grid = UGrid2D::generate(dense_dim(origin, max)?, |coord| {
+            Ok(self.cells.get(&(coord + origin)).unwrap_or(&fill).clone())
+        })?;
+
+        Ok((grid, origin))
+    }
+}
+
+impl<T> FromIterator<(ICoord2D, T)> for SparseGrid2D<T> {
+    fn from_iter<I: IntoIterator<Item = (ICoord2D, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (coord, value) in iter {
+            grid.insert(coord, value);
+        }
+        grid
+    }
+}
+
+// Cells that are not set are drawn as `.`.
+impl<T: CellChar> Display for SparseGrid2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rendered = self
+            .render_with(|cell, _| cell.map_or('.', CellChar::to_char))
+            .map_err(|_| std::fmt::Error)?;
+
+        write!(f, "{rendered}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(x: isize, y: isize) -> ICoord2D {
+        ICoord2D::new(x, y)
+    }
+
+    #[test]
+    fn bounds_grow_and_never_shrink() {
+        let mut grid = SparseGrid2D::new();
+        assert_eq!(grid.bounds(), None);
+        assert!(!grid.in_bounds(c(0, 0)));
+
+        grid.insert(c(1, 2), 'a');
+        assert_eq!(grid.bounds(), Some((c(1, 2), c(1, 2))));
+
+        grid.include(c(-3, 5));
+        grid.insert(c(4, -1), 'b');
+        assert_eq!(grid.bounds(), Some((c(-3, -1), c(4, 5))));
+        assert_eq!(grid.len(), 2);
+        assert!(grid.in_bounds(c(-3, 0)));
+        assert!(!grid.in_bounds(c(5, 0)));
+
+        assert_eq!(grid.remove(c(4, -1)), Some('b'));
+        assert_eq!(grid.bounds(), Some((c(-3, -1), c(4, 5))));
+        assert!(!grid.contains(c(4, -1)));
+    }
+
+    #[test]
+    fn to_dense_reports_its_origin() {
+        let grid = [(c(-2, -1), 1), (c(0, 1), 2)]
+            .into_iter()
+            .collect::<SparseGrid2D<_>>();
+
+        let (dense, origin) = grid.to_dense(0).unwrap();
+        assert_eq!(origin, c(-2, -1));
+        assert_eq!(dense.dim(), UCoord2D::new(3, 3));
+        assert_eq!(*dense.get(UCoord2D::new(0, 0)).unwrap(), 1);
+        assert_eq!(*dense.get(UCoord2D::new(2, 2)).unwrap(), 2);
+        assert_eq!(dense.iter().filter(|&(_, &v)| v == 0).count(), 7);
+
+        let (empty, origin) = SparseGrid2D::<u8>::new().to_dense(0).unwrap();
+        assert_eq!((empty.dim(), origin), (UCoord2D::default(), c(0, 0)));
+    }
+
+    #[test]
+    fn renders_empty_cells_as_dots() {
+        let grid = [(c(-1, 0), '#'), (c(1, 1), 'o')]
+            .into_iter()
+            .collect::<SparseGrid2D<_>>();
+
+        assert_eq!(grid.to_string(), "#..\n..o");
+
+        let rendered = grid
+            .render_with(|cell, coord| match cell {
+                Some(_) => '*',
+                None if coord == c(0, 0) => '+',
+                None => ' ',
+            })
+            .unwrap();
+        assert_eq!(rendered.to_string(), "*+ \n  *");
+        assert_eq!(SparseGrid2D::<char>::new().to_string(), "");
+    }
+
+    #[test]
+    fn neighbors_are_only_set_cells() {
+        let grid = [(c(0, -1), 'n'), (c(1, 1), 'd'), (c(-1, 0), 'w')]
+            .into_iter()
+            .collect::<SparseGrid2D<_>>();
+
+        let four = grid.neighbors4(c(0, 0)).collect::<Vec<_>>();
+        assert_eq!(four, [(c(0, -1), &'n'), (c(-1, 0), &'w')]);
+
+        let eight = grid.neighbors8(c(0, 0)).collect::<Vec<_>>();
+        assert_eq!(eight, [(c(0, -1), &'n'), (c(1, 1), &'d'), (c(-1, 0), &'w')]);
+    }
+
+    #[test]
+    fn parse_keeps_the_bounding_box_of_the_text() {
+        let grid =
+            SparseGrid2D::parse("...\n.#.\n...\n", |ch, _| Ok((ch == '#').then_some(ch))).unwrap();
+
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.get(c(1, 1)), Some(&'#'));
+        assert_eq!(grid.bounds(), Some((c(0, 0), c(2, 2))));
+        assert_eq!(grid.to_string(), "...\n.#.\n...");
+    }
+}
@@ -0,0 +1,54 @@
+use crate::solution::Solution;
+use std::fmt::Display;
+
+// Generates one module per example with a `#[test]` for each listed part:
+//
+//     util::examples! {
+//         Day,
+//         example: EXAMPLE => part1 = 18, part2 = 9;
+//         larger: LARGER => part2 = 1206;
+//     }
+//
+// Answers are compared through their `Display` output, so they can be given as any literal.
+// Attributes in front of an example, such as `#[ignore]`, are applied to each of its tests.
+#[macro_export]
+macro_rules! examples {
+    (@test [$(#[$attr:meta])*], $day:ty, $part:ident, $input:expr, $expected:expr) => {
+        #[test]
+        $(#[$attr])*
+        fn $part() {
+            $crate::testing::$part::<$day>($input, $expected);
+        }
+    };
+    (@example $day:ty, $attrs:tt, $name:ident, $input:expr, [$($part:ident = $expected:expr),+]) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                $crate::examples!(@test $attrs, $day, $part, $input, $expected);
+            )+
+        }
+    };
+    ($day:ty, $($(#[$attr:meta])* $name:ident: $input:expr => $($part:ident = $expected:expr),+;)+) => {
+        $(
+            $crate::examples!(
+                @example $day, [$(#[$attr])*], $name, $input, [$($part = $expected),+]
+            );
+        )+
+    };
+}
+
+pub fn part1<S: Solution>(input: &str, expected: impl Display) {
+    let input = S::parse(input).expect("example should parse");
+    let answer = S::part1(&input).expect("part 1 should solve the example");
+
+    assert_eq!(answer.to_string(), expected.to_string());
+}
+
+pub fn part2<S: Solution>(input: &str, expected: impl Display) {
+    let input = S::parse(input).expect("example should parse");
+    let answer = S::part2(&input).expect("part 2 should solve the example");
+
+    assert_eq!(answer.to_string(), expected.to_string());
+}
@@ -0,0 +1,54 @@
+use util::error::Errors;
+use util::solution::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Box<[i32]>;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| match c {
+                '(' => Ok(1),
+                ')' => Ok(-1),
+                _ => Err(Errors::ParseError),
+            })
+            .collect::<Result<Box<_>, Errors>>()
+    }
+
+    fn part1(seq: &Self::Input) -> Result<i32, Errors> {
+        Ok(seq.iter().sum())
+    }
+
+    fn part2(seq: &Self::Input) -> Result<usize, Errors> {
+        Ok(seq
+            .iter()
+            .scan(0, |state, &v| {
+                *state += v;
+                Some(*state)
+            })
+            .enumerate()
+            .find(|(_, val)| *val == -1)
+            .ok_or(Errors::UncategorizedError("Level -1 not reached".into()))?
+            .0
+            + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        balanced: "(())\n" => part1 = 0;
+        up: "(()(()(\n" => part1 = 3;
+        down: ")())())\n" => part1 = -3;
+        basement_first: ")\n" => part2 = 1;
+        basement_fifth: "()())\n" => part2 = 5;
+    }
+}
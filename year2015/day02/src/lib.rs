@@ -1,8 +1,9 @@
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
-struct Present {
+pub struct Present {
     l: i32,
     w: i32,
     h: i32,
@@ -42,23 +43,37 @@ impl Present {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .to_string()
-        .into_boxed_str();
+pub struct Day;
 
-    let lines = input.as_ref().split('\n').collect::<Box<[&str]>>();
-    let presents = lines
-        .iter()
-        .map(|l| l.parse::<Present>())
-        .collect::<Result<Box<[_]>, _>>()?;
+impl Solution for Day {
+    type Input = Box<[Present]>;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-    let part1: i32 = presents.iter().map(|p| p.wrapping_paper_needed()).sum();
-    println!("{}", part1);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|l| l.parse::<Present>())
+            .collect::<Result<Box<[_]>, _>>()
+    }
 
-    let part1: i32 = presents.iter().map(|p| p.ribbon_needed()).sum();
-    println!("{}", part1);
+    fn part1(presents: &Self::Input) -> Result<i32, Errors> {
+        Ok(presents.iter().map(|p| p.wrapping_paper_needed()).sum())
+    }
 
-    Ok(())
+    fn part2(presents: &Self::Input) -> Result<i32, Errors> {
+        Ok(presents.iter().map(|p| p.ribbon_needed()).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        box_2x3x4: "2x3x4\n" => part1 = 58, part2 = 34;
+        box_1x1x10: "1x1x10\n" => part1 = 43, part2 = 14;
+    }
 }
@@ -0,0 +1,66 @@
+use util::coord::ICoord2D;
+use util::direction::Direction;
+use util::error::Errors;
+use util::solution::Solution;
+use util::sparse_grid::SparseGrid2D;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Box<[Direction]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .chars()
+            .map(Direction::try_from)
+            .collect::<Result<Box<_>, Errors>>()
+    }
+
+    fn part1(seq: &Self::Input) -> Result<usize, Errors> {
+        let mut presents = SparseGrid2D::new();
+        let mut location = ICoord2D::new(0, 0);
+
+        *presents.get_or_insert_with(location, || 0usize) += 1;
+
+        for &dir in seq {
+            location += dir.to_offset();
+            *presents.get_or_insert_with(location, || 0) += 1;
+        }
+
+        Ok(presents.len())
+    }
+
+    fn part2(seq: &Self::Input) -> Result<usize, Errors> {
+        let mut presents = SparseGrid2D::new();
+        let mut locations = [ICoord2D::new(0, 0); 2];
+
+        *presents.get_or_insert_with(locations[0], || 0usize) += 2;
+
+        let mut element = 0;
+        for &dir in seq {
+            let location = &mut locations[element];
+            *location += dir.to_offset();
+            *presents.get_or_insert_with(*location, || 0) += 1;
+
+            element = (element + 1) % 2;
+        }
+
+        Ok(presents.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        single: ">\n" => part1 = 2;
+        square: "^>v<\n" => part1 = 4, part2 = 3;
+        back_and_forth: "^v^v^v^v^v\n" => part1 = 2, part2 = 11;
+        split: "^v\n" => part2 = 3;
+    }
+}
@@ -0,0 +1,55 @@
+use util::error::Errors;
+use util::solution::Solution;
+
+fn check(base: &str, num: u32, count: usize) -> bool {
+    let mut tmp = base.to_owned();
+    tmp += &num.to_string();
+
+    let digest = chksum_md5::chksum(tmp);
+    let hex = digest.unwrap().to_hex_lowercase();
+
+    let first_digits = &hex[0..count];
+
+    first_digits.chars().all(|c| c == '0')
+}
+
+fn find_solution(base: &str, count: usize) -> u32 {
+    let mut x = 0;
+    loop {
+        if check(base, x, count) {
+            return x;
+        }
+        x += 1;
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(input.trim_end().to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32, Errors> {
+        Ok(find_solution(input, 5))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32, Errors> {
+        Ok(find_solution(input, 6))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        abcdef: "abcdef\n" => part1 = 609043;
+        pqrstuv: "pqrstuv\n" => part1 = 1048970;
+    }
+}
@@ -0,0 +1,22 @@
+use util::error::Errors;
+use util::solution::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(_input: &str) -> Result<Self::Input, Errors> {
+        Err(Errors::UncategorizedError("Not solved yet".into()))
+    }
+
+    fn part1(_input: &Self::Input) -> Result<usize, Errors> {
+        Err(Errors::UncategorizedError("Not solved yet".into()))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize, Errors> {
+        Err(Errors::UncategorizedError("Not solved yet".into()))
+    }
+}
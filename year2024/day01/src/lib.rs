@@ -0,0 +1,97 @@
+use util::error::Errors;
+use util::solution::Solution;
+
+#[derive(Copy, Clone, Debug)]
+struct Line {
+    num0: u32,
+    num1: u32,
+}
+
+impl std::str::FromStr for Line {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries: Vec<_> = s.split_whitespace().collect();
+        if entries.len() != 2 {
+            return Err(Errors::ParseError);
+        }
+        Ok(Self {
+            num0: entries[0].parse::<u32>()?,
+            num1: entries[1].parse::<u32>()?,
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<u32>, Vec<u32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let lines = input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Line>())
+            .collect::<Result<Vec<Line>, Errors>>()?;
+
+        let mut col0: Vec<_> = lines.iter().map(|l| l.num0).collect();
+        let mut col1: Vec<_> = lines.iter().map(|l| l.num1).collect();
+        col0.sort();
+        col1.sort();
+
+        Ok((col0, col1))
+    }
+
+    fn part1((col0sorted, col1sorted): &Self::Input) -> Result<u32, Errors> {
+        Ok(col0sorted
+            .iter()
+            .zip(col1sorted.iter())
+            .map(|(a, b)| {
+                let min = std::cmp::min(a, b);
+                let max = std::cmp::max(a, b);
+                max - min
+            })
+            .sum::<u32>())
+    }
+
+    fn part2((col0sorted, col1sorted): &Self::Input) -> Result<u32, Errors> {
+        let occur0 = {
+            let mut tmp = std::collections::HashMap::<u32, u32>::new();
+            for val in col0sorted {
+                *tmp.entry(*val).or_default() += 1;
+            }
+            tmp
+        };
+        let occur1 = {
+            let mut tmp = std::collections::HashMap::<u32, u32>::new();
+            for val in col1sorted {
+                *tmp.entry(*val).or_default() += 1;
+            }
+            tmp
+        };
+        Ok(occur0
+            .iter()
+            .map(|(val, cnt)| val * occur1.get(val).copied().unwrap_or_default() * cnt)
+            .sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 11, part2 = 31;
+    }
+}
@@ -1,7 +1,8 @@
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Clone, Debug)]
-struct Line {
+pub struct Line {
     nums: Vec<u32>,
 }
 
@@ -57,18 +58,45 @@ impl std::str::FromStr for Line {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|line| line.parse::<Line>())
-        .collect::<Result<Vec<Line>, Errors>>()?;
+pub struct Day;
 
-    let result1 = input.iter().filter(|l| l.part1_is_valid()).count();
-    println!("{}", result1);
+impl Solution for Day {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let result2 = input.iter().filter(|l| l.part2_is_valid()).count();
-    println!("{}", result2);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Line>())
+            .collect::<Result<Vec<Line>, Errors>>()
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize, Errors> {
+        Ok(lines.iter().filter(|l| l.part1_is_valid()).count())
+    }
 
-    Ok(())
+    fn part2(lines: &Self::Input) -> Result<usize, Errors> {
+        Ok(lines.iter().filter(|l| l.part2_is_valid()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 2, part2 = 4;
+    }
 }
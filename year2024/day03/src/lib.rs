@@ -0,0 +1,83 @@
+use regex::Regex;
+use std::sync::OnceLock;
+use util::error::Errors;
+use util::solution::Solution;
+
+pub enum Elem {
+    Do,
+    Dont,
+    Mul(u32, u32),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Elem>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let re = REGEX.get_or_init(|| Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)").unwrap());
+
+        re.captures_iter(input.trim_end())
+            .map(|c| -> Result<Elem, Errors> {
+                match &c[0] {
+                    "do()" => Ok(Elem::Do),
+                    "don't()" => Ok(Elem::Dont),
+                    _ => Ok(Elem::Mul(c[1].parse()?, c[2].parse()?)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    fn part1(parsed: &Self::Input) -> Result<u32, Errors> {
+        Ok(parsed
+            .iter()
+            .filter_map(|elem| match elem {
+                Elem::Mul(a, b) => Some(a * b),
+                _ => None,
+            })
+            .sum::<u32>())
+    }
+
+    fn part2(parsed: &Self::Input) -> Result<u32, Errors> {
+        let mut active = true;
+        Ok(parsed
+            .iter()
+            .filter_map(|elem| match elem {
+                Elem::Mul(a, b) => {
+                    if active {
+                        Some(a * b)
+                    } else {
+                        None
+                    }
+                }
+                Elem::Do => {
+                    active = true;
+                    None
+                }
+                Elem::Dont => {
+                    active = false;
+                    None
+                }
+            })
+            .sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n";
+    const EXAMPLE2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n";
+
+    util::examples! {
+        Day,
+        example1: EXAMPLE1 => part1 = 161;
+        example2: EXAMPLE2 => part2 = 48;
+    }
+}
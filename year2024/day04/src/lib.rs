@@ -0,0 +1,149 @@
+use util::coord::{Coord, UCoord2D};
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::solution::Solution;
+
+#[derive(PartialEq)]
+enum Found {
+    N,
+    X,
+    Xm,
+    Xma,
+    Xmas,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = UGrid2D<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let input: Vec<_> = input.trim_end().split('\n').collect();
+        let dim = UCoord2D::new(input[0].len(), input.len());
+
+        UGrid2D::generate(dim, |Coord([x, y])| {
+            input[y]
+                .chars()
+                .nth(x)
+                .ok_or(Errors::UncategorizedError(format!(
+                    "Couldn't access character number {} in input line",
+                    x
+                )))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        let mut grid = input.clone();
+        let mut result = usize::default();
+
+        // Any direction of rotation works, all four orientations are searched.
+        for _ in 0..4 {
+            grid.rotate_left_in_place();
+            for iy in 0..grid.dim().y() {
+                let mut found = Found::N;
+                for ix in 0..grid.dim().x() {
+                    let coord = UCoord2D::new(ix, iy);
+                    let c = grid.get(coord)?;
+                    found = match (found, c) {
+                        (_, 'X') => Found::X,
+                        (Found::X, 'M') => Found::Xm,
+                        (Found::Xm, 'A') => Found::Xma,
+                        (Found::Xma, 'S') => Found::Xmas,
+                        _ => Found::N,
+                    };
+                    if found == Found::Xmas {
+                        result += 1;
+                    }
+                }
+                found = Found::N;
+                for ix in 0..grid.dim().x() {
+                    let cy = ix + iy;
+                    if cy >= grid.dim().y() {
+                        break;
+                    }
+                    let coord = UCoord2D::new(ix, cy);
+                    let c = grid.get(coord)?;
+                    found = match (found, c) {
+                        (_, 'X') => Found::X,
+                        (Found::X, 'M') => Found::Xm,
+                        (Found::Xm, 'A') => Found::Xma,
+                        (Found::Xma, 'S') => Found::Xmas,
+                        _ => Found::N,
+                    };
+                    if found == Found::Xmas {
+                        result += 1;
+                    }
+                }
+            }
+            for ix in 1..grid.dim().x() {
+                let mut found = Found::N;
+                for iy in 0..grid.dim().y() {
+                    let cx = ix + iy;
+                    if cx >= grid.dim().x() {
+                        break;
+                    }
+                    let coord = UCoord2D::new(cx, iy);
+                    let c = grid.get(coord)?;
+                    found = match (found, c) {
+                        (_, 'X') => Found::X,
+                        (Found::X, 'M') => Found::Xm,
+                        (Found::Xm, 'A') => Found::Xma,
+                        (Found::Xma, 'S') => Found::Xmas,
+                        _ => Found::N,
+                    };
+                    if found == Found::Xmas {
+                        result += 1;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, Errors> {
+        let mut result = usize::default();
+
+        for iy in 0..grid.dim().y() - 2 {
+            for ix in 0..grid.dim().x() - 2 {
+                let mut subgrid = grid.sub_grid(UCoord2D::new(ix, iy), UCoord2D::new(3, 3))?;
+                for _ in 0..4 {
+                    subgrid.rotate_left_in_place();
+                    if *subgrid.get(UCoord2D::new(0, 0))? == 'M'
+                        && *subgrid.get(UCoord2D::new(0, 2))? == 'M'
+                        && *subgrid.get(UCoord2D::new(1, 1))? == 'A'
+                        && *subgrid.get(UCoord2D::new(2, 0))? == 'S'
+                        && *subgrid.get(UCoord2D::new(2, 2))? == 'S'
+                    {
+                        result += 1;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 18, part2 = 9;
+    }
+}
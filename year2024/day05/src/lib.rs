@@ -0,0 +1,140 @@
+use std::collections::{HashMap, HashSet};
+use util::error::Errors;
+use util::solution::Solution;
+
+pub struct Input {
+    rules_a_before_b: HashMap<u32, HashSet<u32>>,
+    lists: Vec<Vec<u32>>,
+}
+
+impl Input {
+    fn is_valid(&self, list: &[u32]) -> bool {
+        for idx0 in 1..list.len() {
+            if let Some(e) = self.rules_a_before_b.get(&list[idx0]) {
+                for val1 in &list[0..idx0] {
+                    if e.contains(val1) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let (raw_rules, raw_lists) = input
+            .trim_end()
+            .split_once("\n\n")
+            .ok_or(Errors::ParseError)?;
+
+        let mut rules_a_before_b: HashMap<u32, HashSet<u32>> = HashMap::new();
+
+        for line in raw_rules.split('\n') {
+            let (before, after) = line.split_once('|').ok_or(Errors::ParseError)?;
+            rules_a_before_b
+                .entry(before.parse()?)
+                .or_default()
+                .insert(after.parse()?);
+        }
+
+        let lists = raw_lists
+            .split('\n')
+            .map(|line| {
+                line.split(',')
+                    .map(|p| p.parse::<u32>())
+                    .collect::<Result<Vec<u32>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Input {
+            rules_a_before_b,
+            lists,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32, Errors> {
+        Ok(input
+            .lists
+            .iter()
+            .filter(|list| input.is_valid(list))
+            .map(|list| list[list.len() / 2])
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32, Errors> {
+        let mut result2 = 0u32;
+
+        for list in input.lists.iter().filter(|list| !input.is_valid(list)) {
+            let mut list = list.clone();
+            loop {
+                let mut changed = false;
+                'oloop: for idx0 in 1..list.len() {
+                    if let Some(e) = input.rules_a_before_b.get(&list[idx0]) {
+                        for idx1 in 0..idx0 {
+                            if e.contains(&list[idx1]) {
+                                list.swap(idx0, idx1);
+                                changed = true;
+                                break 'oloop;
+                            }
+                        }
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+            result2 += list[list.len() / 2];
+        }
+
+        Ok(result2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 143, part2 = 123;
+    }
+}
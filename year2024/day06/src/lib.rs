@@ -1,52 +1,53 @@
 use std::collections::HashSet;
-use util::coord2d::{ICoord2D, UCoord2D};
+use util::coord::{Coord, UCoord2D};
 use util::direction::Direction;
-use util::grid2d::UGrid2D;
+use util::error::Errors;
+use util::grid2d::{CellChar, UGrid2D};
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Elem {
+pub enum Elem {
     Open,
     Obstacle,
 }
 
+impl CellChar for Elem {
+    fn to_char(&self) -> char {
+        match self {
+            Elem::Open => '.',
+            Elem::Obstacle => '#',
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 struct GuardState {
     pos: UCoord2D,
     dir: Direction,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input: Vec<_> = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|s| s.to_owned())
-        .collect();
-    let dim = UCoord2D {
-        x: input[0].len(),
-        y: input.len(),
-    };
-
-    let mut guardpos = None;
-
-    let grid = UGrid2D::generate(dim, |UCoord2D { x, y }| {
-        match input[y]
-            .chars()
-            .nth(x)
-            .ok_or(util::error::Errors::DimError(format!("{x} is too high")))?
-        {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (UGrid2D<Elem>, UCoord2D);
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let (grid, markers) = UGrid2D::parse_with_markers(input, &['^'], |c, _| match c {
             '#' => Ok(Elem::Obstacle),
-            '.' => Ok(Elem::Open),
-            '^' => {
-                guardpos = Some(UCoord2D { x, y });
-                Ok(Elem::Open)
-            }
-            _ => panic!("Unexpected map element"),
-        }
-    })?;
+            '.' | '^' => Ok(Elem::Open),
+            _ => Err(Errors::UncategorizedError("Unexpected map element".into())),
+        })?;
+
+        let guard_pos = *markers
+            .get(&'^')
+            .ok_or(Errors::UncategorizedError("Guard expected".into()))?;
 
-    let guard_pos = guardpos.unwrap();
+        Ok((grid, guard_pos))
+    }
 
-    {
+    fn part1(&(ref grid, guard_pos): &Self::Input) -> Result<usize, Errors> {
         let mut guard_pos = guard_pos;
         let mut guard_dir = Direction::North;
         let mut visited_positions = HashSet::new();
@@ -57,10 +58,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let offset = guard_dir.to_offset();
             let new_pos = guard_pos + offset;
             match new_pos {
-                ICoord2D { x, y: _ } if x < 0 => break,
-                ICoord2D { x: _, y } if y < 0 => break,
-                ICoord2D { x, y: _ } if x >= grid.dim().x as isize => break,
-                ICoord2D { x: _, y } if y >= grid.dim().y as isize => break,
+                Coord([x, _]) if x < 0 => break,
+                Coord([_, y]) if y < 0 => break,
+                Coord([x, _]) if x >= grid.dim().x() as isize => break,
+                Coord([_, y]) if y >= grid.dim().y() as isize => break,
                 _ => (),
             };
 
@@ -72,15 +73,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             guard_pos = new_pos;
         }
-        println!("{}", visited_positions.len());
+        Ok(visited_positions.len())
     }
 
-    {
+    fn part2(&(ref grid, guard_pos): &Self::Input) -> Result<u32, Errors> {
         let mut result2 = 0u32;
-        for iy in 0..grid.dim().y {
-            for ix in 0..grid.dim().x {
+        for iy in 0..grid.dim().y() {
+            for ix in 0..grid.dim().x() {
                 let mut grid = grid.clone();
-                let c_coord = UCoord2D { x: ix, y: iy };
+                let c_coord = UCoord2D::new(ix, iy);
                 if *grid.get(c_coord)? == Elem::Obstacle {
                     continue;
                 }
@@ -107,10 +108,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     let offset = guard_state.dir.to_offset();
                     let new_pos = guard_state.pos + offset;
                     match new_pos {
-                        ICoord2D { x, y: _ } if x < 0 => break,
-                        ICoord2D { x: _, y } if y < 0 => break,
-                        ICoord2D { x, y: _ } if x >= grid.dim().x as isize => break,
-                        ICoord2D { x: _, y } if y >= grid.dim().y as isize => break,
+                        Coord([x, _]) if x < 0 => break,
+                        Coord([_, y]) if y < 0 => break,
+                        Coord([x, _]) if x >= grid.dim().x() as isize => break,
+                        Coord([_, y]) if y >= grid.dim().y() as isize => break,
                         _ => (),
                     };
 
@@ -124,8 +125,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
         }
-        println!("{}", result2);
+        Ok(result2)
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 41, part2 = 6;
+    }
 }
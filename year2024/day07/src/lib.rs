@@ -1,7 +1,8 @@
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Clone, Debug)]
-struct Line {
+pub struct Line {
     left: u64,
     right: Vec<u64>,
 }
@@ -98,29 +99,56 @@ impl std::str::FromStr for Line {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|line| line.parse::<Line>())
-        .collect::<Result<Vec<Line>, Errors>>()?;
+pub struct Day;
 
-    println!(
-        "{}",
+impl Solution for Day {
+    type Input = Vec<Line>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
         input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Line>())
+            .collect::<Result<Vec<Line>, Errors>>()
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u64, Errors> {
+        Ok(lines
             .iter()
             .filter(|l| l.eval_part_1())
             .map(|l| l.left)
-            .sum::<u64>()
-    );
+            .sum::<u64>())
+    }
 
-    println!(
-        "{}",
-        input
+    fn part2(lines: &Self::Input) -> Result<u64, Errors> {
+        Ok(lines
             .iter()
             .filter(|l| l.eval_part_2())
             .map(|l| l.left)
-            .sum::<u64>()
-    );
-    Ok(())
+            .sum::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 3749, part2 = 11387;
+    }
 }
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+use util::coord::ICoord2D;
+use util::error::Errors;
+use util::solution::Solution;
+use util::sparse_grid::SparseGrid2D;
+
+pub struct Antennas {
+    grid: SparseGrid2D<char>,
+    map: HashMap<char, HashSet<ICoord2D>>,
+}
+
+impl Antennas {
+    fn contains(&self, loc: ICoord2D) -> bool {
+        self.grid.in_bounds(loc)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Antennas;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let grid = SparseGrid2D::parse(input, |c, _| Ok((c != '.').then_some(c)))?;
+
+        let mut map: HashMap<char, HashSet<ICoord2D>> = HashMap::new();
+        for (loc, &c) in grid.iter() {
+            map.entry(c).or_default().insert(loc);
+        }
+
+        Ok(Antennas { grid, map })
+    }
+
+    fn part1(antennas: &Self::Input) -> Result<usize, Errors> {
+        let mut antinodes1: HashSet<ICoord2D> = HashSet::new();
+
+        for locs in antennas.map.values() {
+            for loc0 in locs {
+                for loc1 in locs {
+                    if loc0 == loc1 {
+                        continue;
+                    }
+                    let antinode_loc = *loc0 - (*loc1 - *loc0);
+                    if !antennas.contains(antinode_loc) {
+                        continue;
+                    }
+                    antinodes1.insert(antinode_loc);
+                }
+            }
+        }
+
+        Ok(antinodes1.len())
+    }
+
+    fn part2(antennas: &Self::Input) -> Result<usize, Errors> {
+        let mut antinodes2: HashSet<ICoord2D> = HashSet::new();
+
+        for locs in antennas.map.values() {
+            for loc0 in locs {
+                for loc1 in locs {
+                    if loc0 == loc1 {
+                        continue;
+                    }
+                    let diff = *loc1 - *loc0;
+                    let mut antinode_loc = *loc0;
+                    while antennas.contains(antinode_loc) {
+                        antinodes2.insert(antinode_loc);
+                        antinode_loc -= diff;
+                    }
+                }
+            }
+        }
+
+        Ok(antinodes2.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 14, part2 = 34;
+    }
+}
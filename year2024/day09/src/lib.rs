@@ -1,6 +1,7 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Copy, Clone, Debug)]
 struct Range {
@@ -8,24 +9,32 @@ struct Range {
     length: usize,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .chars()
-        .map(|c| {
-            c.to_digit(10)
-                .ok_or(Errors::UncategorizedError("Parse problem".into()))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .ok_or(Errors::UncategorizedError("Parse problem".into()))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
         let mut data: Vec<Option<u32>> = Vec::new();
 
         {
             let mut is_file = true;
             let mut file_num = 0u32;
 
-            for v in &input {
+            for v in input {
                 let v = *v;
                 if is_file {
                     assert!(v > 0);
@@ -43,13 +52,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         {
             let mut free_indices = BinaryHeap::new();
 
-            for idx in 0..data.len() {
-                let data_at_idx = data[idx];
-                match data_at_idx {
-                    None => {
-                        free_indices.push(Reverse(idx));
-                    }
-                    Some(_) => (),
+            for (idx, data_at_idx) in data.iter().enumerate() {
+                if data_at_idx.is_none() {
+                    free_indices.push(Reverse(idx));
                 }
             }
             for idx in (0..data.len()).rev() {
@@ -76,10 +81,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .map(|(idx, val)| idx * val.unwrap_or_default() as usize)
             .sum();
 
-        println!("{}", result1);
+        Ok(result1)
     }
 
-    {
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
         let mut files: Vec<(u32, Range)> = Vec::new();
         let mut free_spaces: Vec<Range> = Vec::new();
 
@@ -88,7 +93,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let mut file_num = 0u32;
             let mut idx = usize::default();
 
-            for v in &input {
+            for v in input {
                 let v = *v;
                 let range = Range {
                     start: idx,
@@ -160,8 +165,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             })
             .sum();
 
-        println!("{}", result2);
+        Ok(result2)
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        example: "2333133121414131402\n" => part1 = 1928, part2 = 2858;
+    }
 }
@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+use util::coord::UCoord2D;
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::solution::Solution;
+
+fn score(loc: UCoord2D, grid: &UGrid2D<u32>) -> Result<(usize, usize), Errors> {
+    let climb = |&from: &u32, &to: &u32| to == from + 1;
+
+    let reached = grid
+        .flood_fill(loc, climb)?
+        .into_iter()
+        .filter(|&coord| grid.get(coord).is_ok_and(|&val| val == 9))
+        .count();
+
+    let mut check = VecDeque::from([loc]);
+    let mut trails = 0usize;
+
+    while let Some(candidate) = check.pop_front() {
+        let val = grid.get(candidate)?;
+
+        if *val == 9 {
+            trails += 1;
+            continue;
+        }
+
+        for (gloc, neighbor_val) in grid.neighbors4(candidate) {
+            if climb(val, neighbor_val) {
+                check.push_back(gloc);
+            }
+        }
+    }
+
+    Ok((reached, trails))
+}
+
+fn scores((grid, starts): &(UGrid2D<u32>, Vec<UCoord2D>)) -> Result<(usize, usize), Errors> {
+    starts.iter().map(|start| score(*start, grid)).try_fold(
+        (0usize, 0usize),
+        |(r0, r1), s| -> Result<_, Errors> {
+            let (s0, s1) = s?;
+            Ok((r0 + s0, r1 + s1))
+        },
+    )
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (UGrid2D<u32>, Vec<UCoord2D>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let grid = UGrid2D::parse(input, |c, _| {
+            c.to_digit(10)
+                .ok_or(Errors::UncategorizedError("Invalid digit".into()))
+        })?;
+
+        let starts = grid
+            .iter()
+            .filter(|&(_, &val)| val == 0)
+            .map(|(loc, _)| loc)
+            .collect();
+
+        Ok((grid, starts))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(scores(input)?.0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(scores(input)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 36, part2 = 81;
+    }
+}
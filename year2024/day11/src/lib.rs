@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use util::error::Errors;
+use util::solution::Solution;
 
 fn split_even_num(num: u64) -> Option<(u64, u64)> {
     let digits = num.ilog10() + 1;
@@ -40,7 +42,7 @@ fn stone_expands_to(
 
 fn stones_expand_to(
     remaining_iterations: u8,
-    data: &Vec<u64>,
+    data: &[u64],
     cache: &mut HashMap<(u8, u64), usize>,
 ) -> usize {
     data.iter()
@@ -48,18 +50,36 @@ fn stones_expand_to(
         .sum()
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?.trim_end().to_owned();
+pub struct Day;
 
-    let data = input
-        .split(' ')
-        .map(|v| v.parse::<u64>())
-        .collect::<Result<Vec<_>, _>>()?;
+impl Solution for Day {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let mut cache = HashMap::new();
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(input
+            .trim_end()
+            .split(' ')
+            .map(|v| v.parse::<u64>())
+            .collect::<Result<Vec<_>, _>>()?)
+    }
 
-    println!("{}", stones_expand_to(25, &data, &mut cache));
-    println!("{}", stones_expand_to(75, &data, &mut cache));
+    fn part1(data: &Self::Input) -> Result<usize, Errors> {
+        Ok(stones_expand_to(25, data, &mut HashMap::new()))
+    }
 
-    Ok(())
+    fn part2(data: &Self::Input) -> Result<usize, Errors> {
+        Ok(stones_expand_to(75, data, &mut HashMap::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        example: "125 17\n" => part1 = 55312;
+    }
 }
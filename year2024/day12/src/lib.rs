@@ -0,0 +1,84 @@
+use util::error::Errors;
+use util::grid2d::{Region, UGrid2D};
+use util::solution::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Region>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(UGrid2D::parse(input, |c, _| Ok(c))?.regions())
+    }
+
+    fn part1(regions: &Self::Input) -> Result<usize, Errors> {
+        Ok(regions
+            .iter()
+            .map(|region| region.area() * region.perimeter())
+            .sum())
+    }
+
+    fn part2(regions: &Self::Input) -> Result<usize, Errors> {
+        Ok(regions
+            .iter()
+            .map(|region| region.area() * region.sides())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const SMALL: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+    const NESTED: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+    const E_SHAPE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+    const AB: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+    const LARGE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    util::examples! {
+        Day,
+        small: SMALL => part1 = 140, part2 = 80;
+        nested: NESTED => part1 = 772, part2 = 436;
+        e_shape: E_SHAPE => part2 = 236;
+        ab: AB => part2 = 368;
+        large: LARGE => part1 = 1930, part2 = 1206;
+    }
+}
@@ -2,6 +2,7 @@ use regex::Regex;
 use std::str::FromStr;
 use std::sync::OnceLock;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 struct Matrix {
@@ -59,7 +60,7 @@ impl Vector {
 }
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Entry {
+pub struct Entry {
     matrix: Matrix,
     vector: Vector,
 }
@@ -114,20 +115,54 @@ impl FromStr for Entry {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split("\n\n")
-        .map(|s| s.parse::<Entry>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub struct Day;
 
-    let result1 = input.iter().map(|entry| entry.result1()).sum::<usize>();
+impl Solution for Day {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("{}", result1);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split("\n\n")
+            .map(|s| s.parse::<Entry>())
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    fn part1(entries: &Self::Input) -> Result<usize, Errors> {
+        Ok(entries.iter().map(|entry| entry.result1()).sum::<usize>())
+    }
+
+    fn part2(entries: &Self::Input) -> Result<usize, Errors> {
+        Ok(entries.iter().map(|entry| entry.result2()).sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
 
-    let result2 = input.iter().map(|entry| entry.result2()).sum::<usize>();
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
 
-    println!("{}", result2);
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
 
-    Ok(())
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 480;
+    }
 }
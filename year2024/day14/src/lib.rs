@@ -0,0 +1,173 @@
+use regex::Regex;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use util::coord::{Coord, ICoord2D};
+use util::error::Errors;
+use util::solution::Solution;
+use util::sparse_grid::SparseGrid2D;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Robot {
+    p: ICoord2D,
+    v: ICoord2D,
+}
+
+impl FromStr for Robot {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut robot = Robot::default();
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap());
+
+        let cpt = regex.captures(s).ok_or(Errors::ParseError)?;
+
+        robot.p = ICoord2D::new(cpt[1].parse()?, cpt[2].parse()?);
+        robot.v = ICoord2D::new(cpt[3].parse()?, cpt[4].parse()?);
+
+        Ok(robot)
+    }
+}
+
+fn robot_position_after(dim: ICoord2D, robot: &Robot, steps: usize) -> ICoord2D {
+    robot.p.wrapping_add(robot.v * steps as isize, dim)
+}
+
+fn robot_positions_after(
+    dim: ICoord2D,
+    robots: &[Robot],
+    steps: usize,
+) -> impl Iterator<Item = ICoord2D> + Clone + use<'_> {
+    robots
+        .iter()
+        .map(move |robot| robot_position_after(dim, robot, steps))
+}
+
+fn result1(dim: ICoord2D, robot_positions: impl Iterator<Item = ICoord2D>) -> usize {
+    let pivot = ICoord2D::new((dim.x() - 1) / 2, (dim.y() - 1) / 2);
+
+    let mut counts = [0usize; 4];
+
+    for p in robot_positions {
+        match p {
+            Coord([x, y]) if x < pivot.x() && y < pivot.y() => counts[0] += 1,
+            Coord([x, y]) if x > pivot.x() && y < pivot.y() => counts[1] += 1,
+            Coord([x, y]) if x < pivot.x() && y > pivot.y() => counts[2] += 1,
+            Coord([x, y]) if x > pivot.x() && y > pivot.y() => counts[3] += 1,
+            _ => {}
+        }
+    }
+
+    counts.iter().product()
+}
+
+fn print_map(dim: ICoord2D, robot_positions: impl Iterator<Item = ICoord2D>) -> Result<(), Errors> {
+    let mut map = robot_positions
+        .map(|p| (p, '#'))
+        .collect::<SparseGrid2D<_>>();
+    map.include(ICoord2D::default());
+    map.include(dim - ICoord2D::new(1, 1));
+
+    eprintln!("{map}");
+
+    Ok(())
+}
+
+fn robot_positions_var(robot_positions: impl Iterator<Item = ICoord2D> + Clone) -> f32 {
+    let mut avg_x = 0f32;
+    let mut avg_y = 0f32;
+
+    let positions: Vec<_> = robot_positions.collect();
+
+    for &Coord([x, y]) in &positions {
+        avg_x += x as f32;
+        avg_y += y as f32;
+    }
+    avg_x /= positions.len() as f32;
+    avg_y /= positions.len() as f32;
+
+    let mut var_x = 0f32;
+    let mut var_y = 0f32;
+
+    for &Coord([x, y]) in &positions {
+        var_x += (x as f32 - avg_x) * (x as f32 - avg_x);
+        var_y += (y as f32 - avg_y) * (y as f32 - avg_y);
+    }
+    var_x /= positions.len() as f32;
+    var_y /= positions.len() as f32;
+
+    (var_x + var_y).sqrt()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (ICoord2D, Vec<Robot>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let robots = input
+            .trim_end()
+            .split('\n')
+            .map(|s| s.parse::<Robot>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The area is not part of the input, it is as large as the robots spread out.
+        let start_positions = robots
+            .iter()
+            .map(|robot| (robot.p, ()))
+            .collect::<SparseGrid2D<_>>();
+        let dim = start_positions
+            .bounds()
+            .map_or(ICoord2D::default(), |(_, max)| max + ICoord2D::new(1, 1));
+
+        Ok((dim, robots))
+    }
+
+    fn part1(&(dim, ref robots): &Self::Input) -> Result<usize, Errors> {
+        Ok(result1(dim, robot_positions_after(dim, robots, 100)))
+    }
+
+    fn part2(&(dim, ref robots): &Self::Input) -> Result<usize, Errors> {
+        let mut min_var = f32::MAX;
+        let mut cnt_at_min_var = 0usize;
+
+        for i in 0..dim.x() * dim.y() {
+            let var = robot_positions_var(robot_positions_after(dim, robots, i as usize + 1));
+
+            if var < min_var {
+                min_var = var;
+                cnt_at_min_var = i as usize + 1;
+            }
+        }
+
+        print_map(dim, robot_positions_after(dim, robots, cnt_at_min_var))?;
+
+        Ok(cnt_at_min_var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 12;
+    }
+}
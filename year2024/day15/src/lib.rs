@@ -1,9 +1,11 @@
 use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
-use util::coord2d::{ICoord2D, UCoord2D};
+use util::coord::{Coord, ICoord2D, UCoord2D};
 use util::direction::Direction;
 use util::error::Errors;
-use util::grid2d::UGrid2D;
+use util::grid2d::{CellChar, UGrid2D};
+use util::solution::Solution;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum MapElem {
@@ -11,35 +13,66 @@ enum MapElem {
     Wall,
 }
 
+impl CellChar for MapElem {
+    fn to_char(&self) -> char {
+        match self {
+            MapElem::Space => '.',
+            MapElem::Wall => '#',
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
-struct World {
+pub struct World {
     map: UGrid2D<MapElem>,
     box_positions: HashSet<ICoord2D>,
     robot_position: ICoord2D,
+    wide: bool,
+}
+
+// Draws the warehouse as in the puzzle, with wide boxes as `[]` after `transform_part2`.
+impl Display for World {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let map = self.map.render_with(|elem, _| elem.to_char());
+        let map = if self.wide {
+            map.overlay(self.box_positions.iter().copied(), '[')
+                .overlay(
+                    self.box_positions
+                        .iter()
+                        .map(|&pos| pos + Direction::East.to_offset()),
+                    ']',
+                )
+        } else {
+            map.overlay(self.box_positions.iter().copied(), 'O')
+        };
+
+        write!(f, "{}", map.overlay([self.robot_position], '@'))
+    }
 }
 
 impl World {
     fn transform_part2(&self) -> Result<World, Errors> {
         let mut dim = self.map.dim();
-        dim.x *= 2;
+        *dim.x_mut() *= 2;
 
-        let map = UGrid2D::generate(dim, |UCoord2D { x, y }| {
-            Ok(*(self.map.get(UCoord2D { x: x / 2, y })?))
+        let map = UGrid2D::generate(dim, |Coord([x, y])| {
+            Ok(*(self.map.get(UCoord2D::new(x / 2, y))?))
         })?;
 
         let box_positions = self
             .box_positions
             .iter()
-            .map(|&ICoord2D { x, y }| ICoord2D { x: x * 2, y })
+            .map(|&Coord([x, y])| ICoord2D::new(x * 2, y))
             .collect::<HashSet<_>>();
 
         let mut robot_position = self.robot_position;
-        robot_position.x *= 2;
+        *robot_position.x_mut() *= 2;
 
         Ok(Self {
             map,
             box_positions,
             robot_position,
+            wide: true,
         })
     }
 
@@ -48,7 +81,7 @@ impl World {
 
         let mut check = self.robot_position + offset;
         while self.box_positions.contains(&check) {
-            check = check + offset;
+            check += offset;
         }
 
         if *self.map.get(
@@ -141,13 +174,13 @@ impl World {
             self.box_positions.insert(o + move_offset);
         }
 
-        self.robot_position = self.robot_position + move_offset;
+        self.robot_position += move_offset;
 
         Ok(())
     }
 }
 
-fn to_gps(ICoord2D { x, y }: ICoord2D) -> usize {
+fn to_gps(Coord([x, y]): ICoord2D) -> usize {
     x as usize + 100 * y as usize
 }
 
@@ -162,84 +195,118 @@ fn result(world: &World) -> usize {
 impl FromStr for World {
     type Err = Errors;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines = s.split('\n').collect::<Vec<_>>();
-
-        let dim = UCoord2D {
-            x: lines[0].len(),
-            y: lines.len(),
-        };
-
-        let mut robot_position = ICoord2D::default();
         let mut box_positions = HashSet::new();
 
-        let map = UGrid2D::generate(dim, |UCoord2D { x, y }| {
-            match lines[y]
-                .chars()
-                .nth(x)
-                .ok_or(Errors::DimError("Invalid index for string".into()))?
-            {
-                '#' => Ok(MapElem::Wall),
-                '.' => Ok(MapElem::Space),
-                'O' => {
-                    box_positions.insert(ICoord2D {
-                        x: x as isize,
-                        y: y as isize,
-                    });
-                    Ok(MapElem::Space)
-                }
-                '@' => {
-                    robot_position = ICoord2D {
-                        x: x as isize,
-                        y: y as isize,
-                    };
-                    Ok(MapElem::Space)
-                }
-                _ => Err(Errors::UncategorizedError("Invalid map input".into())),
+        let (map, markers) = UGrid2D::parse_with_markers(s, &['@'], |c, coord| match c {
+            '#' => Ok(MapElem::Wall),
+            '.' | '@' => Ok(MapElem::Space),
+            'O' => {
+                box_positions.insert(ICoord2D::try_from(coord)?);
+                Ok(MapElem::Space)
             }
+            _ => Err(Errors::UncategorizedError("Invalid map input".into())),
         })?;
 
+        let robot_position = ICoord2D::try_from(
+            *markers
+                .get(&'@')
+                .ok_or(Errors::UncategorizedError("Robot expected".into()))?,
+        )?;
+
         Ok(Self {
             map,
             robot_position,
             box_positions,
+            wide: false,
         })
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let raw_input = std::fs::read_to_string("input")?;
-    let input: Vec<_> = raw_input.trim_end().split("\n\n").collect();
-
-    let world = input[0].parse::<World>()?;
-    let input_directions = input[1]
-        .split("\n")
-        .collect::<Vec<_>>()
-        .join("")
-        .chars()
-        .map(|c| match c {
-            '^' => Direction::North,
-            '>' => Direction::East,
-            'v' => Direction::South,
-            '<' => Direction::West,
-            _ => panic!("Invalid direction"),
-        })
-        .collect::<Vec<_>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (World, Vec<Direction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    {
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let input: Vec<_> = input.trim_end().split("\n\n").collect();
+
+        let world = input[0].parse::<World>()?;
+        let input_directions = input[1]
+            .split("\n")
+            .collect::<Vec<_>>()
+            .join("")
+            .chars()
+            .map(Direction::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((world, input_directions))
+    }
+
+    fn part1((world, input_directions): &Self::Input) -> Result<usize, Errors> {
         let mut world_part1 = world.clone();
-        for &dir in &input_directions {
+
+        for &dir in input_directions {
             world_part1.simulate_part1(dir)?;
         }
-        println!("{}", result(&world_part1));
+
+        Ok(result(&world_part1))
     }
 
-    {
+    fn part2((world, input_directions): &Self::Input) -> Result<usize, Errors> {
         let mut world_part2 = world.transform_part2()?;
-        for &dir in &input_directions {
+        for &dir in input_directions {
             world_part2.simulate_part2(dir)?;
         }
-        println!("{}", result(&world_part2));
+
+        Ok(result(&world_part2))
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+    const LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    util::examples! {
+        Day,
+        small: SMALL => part1 = 2028;
+        large: LARGE => part1 = 10092, part2 = 9021;
+    }
 }
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use util::coord::ICoord2D;
+use util::direction::Direction;
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::search::{Search, dijkstra_all_paths};
+use util::solution::Solution;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum MapElem {
+    Space,
+    Wall,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct ReindeerState {
+    position: ICoord2D,
+    direction: Direction,
+}
+
+impl ReindeerState {
+    fn next_states(&self) -> [ReindeerCheckState; 3] {
+        [
+            ReindeerCheckState {
+                state: ReindeerState {
+                    position: self.position + self.direction.to_offset(),
+                    direction: self.direction,
+                },
+                cost: 1,
+            },
+            ReindeerCheckState {
+                state: ReindeerState {
+                    position: self.position,
+                    direction: self.direction.rotate_left(),
+                },
+                cost: 1000,
+            },
+            ReindeerCheckState {
+                state: ReindeerState {
+                    position: self.position,
+                    direction: self.direction.rotate_right(),
+                },
+                cost: 1000,
+            },
+        ]
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ReindeerCheckState {
+    state: ReindeerState,
+    cost: usize,
+}
+
+pub struct Maze {
+    start: ICoord2D,
+    end: ICoord2D,
+    map: UGrid2D<MapElem>,
+}
+
+fn search(maze: &Maze) -> Search<ReindeerState, usize> {
+    let reindeer_start = ReindeerState {
+        position: maze.start,
+        direction: Direction::East,
+    };
+
+    dijkstra_all_paths(
+        reindeer_start,
+        |state| {
+            state.next_states().into_iter().filter_map(|next| {
+                (maze.map.get(next.state.position).ok() == Some(&MapElem::Space))
+                    .then_some((next.state, next.cost))
+            })
+        },
+        |state| state.position == maze.end,
+    )
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let (map, markers) = UGrid2D::parse_with_markers(input, &['S', 'E'], |c, _| match c {
+            '.' | 'S' | 'E' => Ok(MapElem::Space),
+            '#' => Ok(MapElem::Wall),
+            _ => Err(Errors::UncategorizedError(
+                "Invalid map input character".into(),
+            )),
+        })?;
+
+        let marker = |c| {
+            markers
+                .get(&c)
+                .ok_or(Errors::UncategorizedError(format!("Marker {c} expected")))
+                .and_then(|&coord| ICoord2D::try_from(coord))
+        };
+        let start = marker('S')?;
+        let end = marker('E')?;
+
+        Ok(Maze { start, end, map })
+    }
+
+    fn part1(maze: &Self::Input) -> Result<usize, Errors> {
+        search(maze)
+            .goal_distance()
+            .ok_or(Errors::UncategorizedError("End not reachable".into()))
+    }
+
+    fn part2(maze: &Self::Input) -> Result<usize, Errors> {
+        let on_winning_paths = search(maze)
+            .all_optimal_paths_states()
+            .into_iter()
+            .map(|state| state.position)
+            .collect::<HashSet<_>>();
+
+        Ok(on_winning_paths.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const FIRST: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+    const SECOND: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    util::examples! {
+        Day,
+        first: FIRST => part1 = 7036, part2 = 45;
+        second: SECOND => part1 = 11048, part2 = 64;
+    }
+}
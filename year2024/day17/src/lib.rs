@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 use util::error::Errors;
+use util::solution::Solution;
 
 type Value = u64;
 
@@ -63,7 +64,7 @@ impl Display for ComboOperand {
 }
 
 #[derive(Clone, Debug)]
-struct Machine {
+pub struct Machine {
     registers: [Value; 3],
     program: Vec<Value>,
     instruction_ptr: usize,
@@ -228,6 +229,10 @@ fn join_output(output: &[Value]) -> String {
 fn find_solution_2(machine: Machine, base: Value) -> Option<Value> {
     for offset in 0..8 {
         let candidate = base + offset;
+        // A zero candidate would recurse with the same base forever.
+        if candidate == 0 {
+            continue;
+        }
         let output = machine.clone().output_for_a(candidate);
         let nth_from_back = output.len() - 1;
         if machine
@@ -255,11 +260,16 @@ fn find_solution_2(machine: Machine, base: Value) -> Option<Value> {
     None
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let raw_input = std::fs::read_to_string("input")?;
-    let raw_lines = raw_input.trim_end().split('\n').collect::<Vec<_>>();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Machine;
+    type Answer1 = String;
+    type Answer2 = Value;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let raw_lines = input.trim_end().split('\n').collect::<Vec<_>>();
 
-    let machine = {
         let registers = [
             extract_register(raw_lines[0])?,
             extract_register(raw_lines[1])?,
@@ -267,20 +277,52 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ];
         let program = extract_program(raw_lines[4])?;
 
-        Machine {
+        Ok(Machine {
             registers,
             program,
             instruction_ptr: 0,
-        }
-    };
+        })
+    }
 
-    println!("{}", join_output(&machine.clone().output()));
+    fn part1(input: &Self::Input) -> Result<String, Errors> {
+        let machine = input.clone();
 
-    println!("\n{}\n", machine.disassemble().join("\n"));
+        Ok(join_output(&machine.output()))
+    }
 
-    let result2 = find_solution_2(machine.clone(), 0).expect("Solution expected for part 2");
+    fn part2(input: &Self::Input) -> Result<Value, Errors> {
+        let machine = input.clone();
 
-    println!("{}", result2);
+        eprintln!("\n{}\n", machine.disassemble().join("\n"));
 
-    Ok(())
+        find_solution_2(machine, 0).ok_or(Errors::UncategorizedError(
+            "Solution expected for part 2".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+    const EXAMPLE2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    util::examples! {
+        Day,
+        example1: EXAMPLE1 => part1 = "4,6,3,5,6,3,5,2,1,0";
+        example2: EXAMPLE2 => part2 = 117440;
+    }
 }
@@ -0,0 +1,170 @@
+use util::coord::UCoord2D;
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::search::dijkstra;
+use util::solution::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+enum Field {
+    #[default]
+    Open,
+    Closed,
+}
+
+fn calculate_cost(grid: UGrid2D<Field>) -> Result<usize, Errors> {
+    let start = grid.dim() - UCoord2D::new(1, 1);
+    if *grid.get(start)? == Field::Closed {
+        return Ok(usize::MAX);
+    }
+
+    let search = dijkstra(
+        start,
+        |&coord| {
+            grid.neighbors4(coord)
+                .filter(|&(_, &field)| field == Field::Open)
+                .map(|(neighbor, _)| (neighbor, 1))
+        },
+        |&coord| coord == UCoord2D::default(),
+    );
+
+    Ok(search.goal_distance().unwrap_or(usize::MAX))
+}
+
+fn make_work_grid<'a>(
+    dim: UCoord2D,
+    coords: impl Iterator<Item = &'a UCoord2D>,
+) -> Result<UGrid2D<Field>, Errors> {
+    let mut result = UGrid2D::from_default(dim);
+
+    for &coord in coords {
+        *result.get_mut(coord)? = Field::Closed;
+    }
+
+    Ok(result)
+}
+
+fn min_steps_after(dim: UCoord2D, coords: &[UCoord2D], fallen: usize) -> Result<usize, Errors> {
+    calculate_cost(make_work_grid(dim, coords.iter().take(fallen))?)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (UCoord2D, Vec<UCoord2D>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let raw_lines = input.trim_end().split('\n').collect::<Vec<_>>();
+
+        let coords = raw_lines
+            .iter()
+            .map(|line| line.parse::<UCoord2D>())
+            .collect::<Result<Vec<_>, Errors>>()?;
+
+        let dim = coords
+            .iter()
+            .copied()
+            .reduce(UCoord2D::component_max)
+            .expect("Values expected in input")
+            + UCoord2D::new(1, 1);
+
+        Ok((dim, coords))
+    }
+
+    fn part1(&(dim, ref coords): &Self::Input) -> Result<usize, Errors> {
+        min_steps_after(dim, coords, 1024)
+    }
+
+    fn part2(&(dim, ref coords): &Self::Input) -> Result<String, Errors> {
+        let mut costs: Vec<Option<usize>> = vec![None; coords.len()];
+        let mut min = 1;
+        let mut max = coords.len() - 1;
+
+        let solution_part2 = loop {
+            let candidate = min + (max - min) / 2;
+            {
+                let cost_cadidate = &mut costs[candidate];
+                let cost_candidate = cost_cadidate.get_or_insert_with(|| {
+                    calculate_cost(
+                        make_work_grid(dim, coords.iter().take(candidate + 1))
+                            .expect("Valid grid expected"),
+                    )
+                    .expect("Cost expected")
+                });
+
+                if *cost_candidate < usize::MAX {
+                    min = candidate + 1;
+                    continue;
+                }
+            }
+            {
+                let cost_prev = &mut costs[candidate - 1];
+                let cost_prev = cost_prev.get_or_insert_with(|| {
+                    calculate_cost(
+                        make_work_grid(dim, coords.iter().take(candidate))
+                            .expect("Valid grid expected"),
+                    )
+                    .expect("Cost expected")
+                });
+
+                if *cost_prev == usize::MAX {
+                    max = candidate - 1;
+                    continue;
+                }
+            }
+
+            break coords[candidate];
+        };
+
+        Ok(solution_part2.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    use super::min_steps_after;
+    use util::solution::Solution;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part2 = "6,1";
+    }
+
+    #[test]
+    fn example_after_12_bytes() {
+        let (dim, coords) = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(min_steps_after(dim, &coords, 12).unwrap(), 22);
+    }
+}
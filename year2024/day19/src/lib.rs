@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use util::error::Errors;
+use util::solution::Solution;
+
+fn num_assemblies<'a>(
+    target: &'a str,
+    splits: &[&str],
+    cache: &mut HashMap<&'a str, usize>,
+) -> usize {
+    let mut result = 0usize;
+    if target.is_empty() {
+        return 1;
+    }
+
+    if let Some(&cache_entry) = cache.get(target) {
+        return cache_entry;
+    }
+
+    for s in splits {
+        if target.starts_with(s) {
+            let new_target = &target[s.len()..target.len()];
+            result += num_assemblies(new_target, splits, cache);
+        }
+    }
+
+    cache.insert(target, result);
+
+    result
+}
+
+fn assemblies((towels, targets): &(Vec<String>, Vec<String>)) -> Vec<usize> {
+    let towels = towels.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let mut cache = HashMap::new();
+
+    targets
+        .iter()
+        .map(|target| num_assemblies(target, &towels, &mut cache))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let (raw_towels, raw_targets) = input
+            .trim_end()
+            .split_once("\n\n")
+            .ok_or(Errors::ParseError)?;
+
+        Ok((
+            raw_towels.split(", ").map(String::from).collect(),
+            raw_targets.split('\n').map(String::from).collect(),
+        ))
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(assemblies(input).iter().filter(|&&n| n != 0).count())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(assemblies(input).iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 6, part2 = 16;
+    }
+}
@@ -0,0 +1,120 @@
+use util::coord::TryAsICoord;
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::solution::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Field {
+    Open,
+    Closed,
+}
+
+fn solution(
+    dist_map: &UGrid2D<Option<usize>>,
+    cheat_time: usize,
+    min_saving: usize,
+) -> Result<usize, Errors> {
+    let mut result = 0usize;
+
+    for (from, &from_dist) in dist_map.iter() {
+        let Some(from_dist) = from_dist else {
+            continue;
+        };
+
+        let from = from.try_as_icoord()?;
+        for to in from.within_manhattan(cheat_time) {
+            let Ok(&Some(to_dist)) = dist_map.get(to) else {
+                continue;
+            };
+
+            let cheat_to_dist = from_dist + from.manhattan(to);
+            if to_dist > cheat_to_dist && to_dist - cheat_to_dist >= min_saving {
+                result += 1;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = UGrid2D<Option<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let (grid, markers) = UGrid2D::parse_with_markers(input, &['S'], |c, _| match c {
+            '#' => Ok(Field::Closed),
+            '.' | 'S' | 'E' => Ok(Field::Open),
+            _ => Err(Errors::UncategorizedError("Invalid input".into())),
+        })?;
+
+        let start = *markers
+            .get(&'S')
+            .ok_or(Errors::UncategorizedError("Start expected".into()))?;
+
+        let dist_map = grid.bfs_distances(start, |_, &to| to == Field::Open)?;
+
+        Ok(dist_map)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        solution(input, 2, 100)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        solution(input, 20, 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    use super::solution;
+    use util::solution::Solution;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    // No cheat in the example saves the 100 picoseconds the real puzzle asks for.
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 0, part2 = 0;
+    }
+
+    #[test]
+    fn example_short_cheats() {
+        let dist_map = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(solution(&dist_map, 2, 64).unwrap(), 1);
+        assert_eq!(solution(&dist_map, 2, 40).unwrap(), 2);
+        assert_eq!(solution(&dist_map, 2, 12).unwrap(), 8);
+    }
+
+    #[test]
+    fn example_long_cheats() {
+        let dist_map = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(solution(&dist_map, 20, 76).unwrap(), 3);
+        assert_eq!(solution(&dist_map, 20, 74).unwrap(), 7);
+        assert_eq!(solution(&dist_map, 20, 50).unwrap(), 285);
+    }
+}
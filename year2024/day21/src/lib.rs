@@ -1,6 +1,7 @@
 use std::collections::HashMap;
-use util::coord2d::ICoord2D;
+use util::coord::ICoord2D;
 use util::error::Errors;
+use util::solution::Solution;
 
 mod detail {
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -34,17 +35,17 @@ impl NumericKeyboard {
     fn location(self) -> ICoord2D {
         use NumericKeyboard::*;
         match self {
-            Key7 => ICoord2D { x: 0, y: 0 },
-            Key8 => ICoord2D { x: 1, y: 0 },
-            Key9 => ICoord2D { x: 2, y: 0 },
-            Key4 => ICoord2D { x: 0, y: 1 },
-            Key5 => ICoord2D { x: 1, y: 1 },
-            Key6 => ICoord2D { x: 2, y: 1 },
-            Key1 => ICoord2D { x: 0, y: 2 },
-            Key2 => ICoord2D { x: 1, y: 2 },
-            Key3 => ICoord2D { x: 2, y: 2 },
-            Key0 => ICoord2D { x: 1, y: 3 },
-            KeyA => ICoord2D { x: 2, y: 3 },
+            Key7 => ICoord2D::new(0, 0),
+            Key8 => ICoord2D::new(1, 0),
+            Key9 => ICoord2D::new(2, 0),
+            Key4 => ICoord2D::new(0, 1),
+            Key5 => ICoord2D::new(1, 1),
+            Key6 => ICoord2D::new(2, 1),
+            Key1 => ICoord2D::new(0, 2),
+            Key2 => ICoord2D::new(1, 2),
+            Key3 => ICoord2D::new(2, 2),
+            Key0 => ICoord2D::new(1, 3),
+            KeyA => ICoord2D::new(2, 3),
         }
     }
 
@@ -59,11 +60,11 @@ impl NumericKeyboard {
 
         match (loc_self, loc_to) {
             (f, t) if t == f => vec![detail::Sequence { sequence: vec![] }],
-            (f, t) if t.x < f.x && t.y == f.y => vec![detail::Sequence {
+            (f, t) if t.x() < f.x() && t.y() == f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyL,
-                        repetitions: f.x - t.x,
+                        repetitions: f.x() - t.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -71,11 +72,11 @@ impl NumericKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x > f.x && t.y == f.y => vec![detail::Sequence {
+            (f, t) if t.x() > f.x() && t.y() == f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyR,
-                        repetitions: t.x - f.x,
+                        repetitions: t.x() - f.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -83,11 +84,11 @@ impl NumericKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x == f.x && t.y < f.y => vec![detail::Sequence {
+            (f, t) if t.x() == f.x() && t.y() < f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyU,
-                        repetitions: f.y - t.y,
+                        repetitions: f.y() - t.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -95,11 +96,11 @@ impl NumericKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x == f.x && t.y > f.y => vec![detail::Sequence {
+            (f, t) if t.x() == f.x() && t.y() > f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyD,
-                        repetitions: t.y - f.y,
+                        repetitions: t.y() - f.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -107,15 +108,15 @@ impl NumericKeyboard {
                     },
                 ],
             }],
-            (f, t) if f.x == 0 && t.y == 3 => vec![detail::Sequence {
+            (f, t) if f.x() == 0 && t.y() == 3 => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyR,
-                        repetitions: t.x - f.x,
+                        repetitions: t.x() - f.x(),
                     },
                     detail::SequenceElement {
                         key: KeyD,
-                        repetitions: t.y - f.y,
+                        repetitions: t.y() - f.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -123,15 +124,15 @@ impl NumericKeyboard {
                     },
                 ],
             }],
-            (f, t) if f.y == 3 && t.x == 0 => vec![detail::Sequence {
+            (f, t) if f.y() == 3 && t.x() == 0 => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyU,
-                        repetitions: f.y - t.y,
+                        repetitions: f.y() - t.y(),
                     },
                     detail::SequenceElement {
                         key: KeyL,
-                        repetitions: f.x - t.x,
+                        repetitions: f.x() - t.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -141,10 +142,10 @@ impl NumericKeyboard {
             }],
             (f, t) => {
                 let diff = t - f;
-                let key_x = if diff.x < 0 { KeyL } else { KeyR };
-                let key_y = if diff.y < 0 { KeyU } else { KeyD };
-                let repetitions_x = diff.x.abs();
-                let repetitions_y = diff.y.abs();
+                let key_x = if diff.x() < 0 { KeyL } else { KeyR };
+                let key_y = if diff.y() < 0 { KeyU } else { KeyD };
+                let repetitions_x = diff.x().abs();
+                let repetitions_y = diff.y().abs();
 
                 assert_ne!(repetitions_x, 0);
                 assert_ne!(repetitions_y, 0);
@@ -195,7 +196,7 @@ impl NumericKeyboard {
     ) -> usize {
         let sequences = state.parent_sequence_candidates(button);
 
-        let min_cost = sequences
+        sequences
             .iter()
             .map(|sequence| {
                 let mut cost = 0usize;
@@ -218,9 +219,7 @@ impl NumericKeyboard {
                 cost
             })
             .min()
-            .expect("Minimum expected to exist");
-
-        min_cost
+            .expect("Minimum expected to exist")
     }
 }
 
@@ -258,11 +257,11 @@ impl DirectionalKeyboard {
     fn location(self) -> ICoord2D {
         use DirectionalKeyboard::*;
         match self {
-            KeyU => ICoord2D { x: 1, y: 0 },
-            KeyA => ICoord2D { x: 2, y: 0 },
-            KeyL => ICoord2D { x: 0, y: 1 },
-            KeyD => ICoord2D { x: 1, y: 1 },
-            KeyR => ICoord2D { x: 2, y: 1 },
+            KeyU => ICoord2D::new(1, 0),
+            KeyA => ICoord2D::new(2, 0),
+            KeyL => ICoord2D::new(0, 1),
+            KeyD => ICoord2D::new(1, 1),
+            KeyR => ICoord2D::new(2, 1),
         }
     }
 
@@ -282,11 +281,11 @@ impl DirectionalKeyboard {
                     repetitions: 1,
                 }],
             }],
-            (f, t) if t.x < f.x && t.y == f.y => vec![detail::Sequence {
+            (f, t) if t.x() < f.x() && t.y() == f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyL,
-                        repetitions: f.x - t.x,
+                        repetitions: f.x() - t.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -294,11 +293,11 @@ impl DirectionalKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x > f.x && t.y == f.y => vec![detail::Sequence {
+            (f, t) if t.x() > f.x() && t.y() == f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyR,
-                        repetitions: t.x - f.x,
+                        repetitions: t.x() - f.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -306,11 +305,11 @@ impl DirectionalKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x == f.x && t.y < f.y => vec![detail::Sequence {
+            (f, t) if t.x() == f.x() && t.y() < f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyU,
-                        repetitions: f.y - t.y,
+                        repetitions: f.y() - t.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -318,11 +317,11 @@ impl DirectionalKeyboard {
                     },
                 ],
             }],
-            (f, t) if t.x == f.x && t.y > f.y => vec![detail::Sequence {
+            (f, t) if t.x() == f.x() && t.y() > f.y() => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyD,
-                        repetitions: t.y - f.y,
+                        repetitions: t.y() - f.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -330,15 +329,15 @@ impl DirectionalKeyboard {
                     },
                 ],
             }],
-            (f, t) if f.x == 0 && t.y == 0 => vec![detail::Sequence {
+            (f, t) if f.x() == 0 && t.y() == 0 => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyR,
-                        repetitions: t.x - f.x,
+                        repetitions: t.x() - f.x(),
                     },
                     detail::SequenceElement {
                         key: KeyU,
-                        repetitions: f.y - t.y,
+                        repetitions: f.y() - t.y(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -346,15 +345,15 @@ impl DirectionalKeyboard {
                     },
                 ],
             }],
-            (f, t) if f.y == 0 && t.x == 0 => vec![detail::Sequence {
+            (f, t) if f.y() == 0 && t.x() == 0 => vec![detail::Sequence {
                 sequence: vec![
                     detail::SequenceElement {
                         key: KeyD,
-                        repetitions: t.y - f.y,
+                        repetitions: t.y() - f.y(),
                     },
                     detail::SequenceElement {
                         key: KeyL,
-                        repetitions: f.x - t.x,
+                        repetitions: f.x() - t.x(),
                     },
                     detail::SequenceElement {
                         key: DirectionalKeyboard::KeyA,
@@ -364,10 +363,10 @@ impl DirectionalKeyboard {
             }],
             (f, t) => {
                 let diff = t - f;
-                let key_x = if diff.x < 0 { KeyL } else { KeyR };
-                let key_y = if diff.y < 0 { KeyU } else { KeyD };
-                let repetitions_x = diff.x.abs();
-                let repetitions_y = diff.y.abs();
+                let key_x = if diff.x() < 0 { KeyL } else { KeyR };
+                let key_y = if diff.y() < 0 { KeyU } else { KeyD };
+                let repetitions_x = diff.x().abs();
+                let repetitions_y = diff.y().abs();
 
                 assert_ne!(repetitions_x, 0);
                 assert_ne!(repetitions_y, 0);
@@ -454,13 +453,13 @@ impl DirectionalKeyboard {
     }
 }
 
-struct Line {
+pub struct Line {
     sequence: Vec<NumericKeyboard>,
     value: usize,
 }
 
 fn result(
-    lines: &Vec<Line>,
+    lines: &[Line],
     directional_robot_keypads: usize,
     cache: &mut HashMap<(DirectionalKeyboard, DirectionalKeyboard, usize), usize>,
 ) -> usize {
@@ -478,26 +477,51 @@ fn result(
         .sum()
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let raw_input = std::fs::read_to_string("input")?;
-    let raw_lines = raw_input.trim_end().split('\n').collect::<Vec<_>>();
-
-    let lines = raw_lines
-        .iter()
-        .map(|l| -> Result<_, Errors> {
-            let sequence = l
-                .chars()
-                .map(|c| c.try_into())
-                .collect::<Result<Vec<NumericKeyboard>, Errors>>()?;
-            let value = l[0..3].parse::<usize>()?;
-            Ok(Line { sequence, value })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|l| -> Result<_, Errors> {
+                let sequence = l
+                    .chars()
+                    .map(|c| c.try_into())
+                    .collect::<Result<Vec<NumericKeyboard>, Errors>>()?;
+                let value = l[0..3].parse::<usize>()?;
+                Ok(Line { sequence, value })
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
 
-    let mut cache = HashMap::new();
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(result(input, 2, &mut HashMap::new()))
+    }
 
-    println!("{}", result(&lines, 2, &mut cache));
-    println!("{}", result(&lines, 25, &mut cache));
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(result(input, 25, &mut HashMap::new()))
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 126384;
+    }
 }
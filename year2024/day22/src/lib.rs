@@ -0,0 +1,123 @@
+use util::error::Errors;
+use util::solution::Solution;
+
+type UsedInteger = isize;
+
+const MAX_ABS_PRICE_DIFF: usize = 9;
+const MAX_PRICE_DIFF_VALUES: usize = MAX_ABS_PRICE_DIFF * 2 + 1;
+const ARRAY_SIZE: usize =
+    MAX_PRICE_DIFF_VALUES * MAX_PRICE_DIFF_VALUES * MAX_PRICE_DIFF_VALUES * MAX_PRICE_DIFF_VALUES;
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+struct PriceChangeSequence {
+    sequence: [UsedInteger; 4],
+}
+
+impl PriceChangeSequence {
+    fn push_back(&mut self, price_change: UsedInteger) {
+        self.sequence = [
+            self.sequence[1],
+            self.sequence[2],
+            self.sequence[3],
+            price_change,
+        ];
+    }
+
+    fn to_idx(self) -> usize {
+        let s = self
+            .sequence
+            .map(|v| (v + MAX_ABS_PRICE_DIFF as isize) as usize);
+        s[0] + (s[1] + (s[2] + s[3] * MAX_PRICE_DIFF_VALUES) * MAX_PRICE_DIFF_VALUES)
+            * MAX_PRICE_DIFF_VALUES
+    }
+}
+
+fn new_secret(old_secret: UsedInteger) -> UsedInteger {
+    const PRUNE: UsedInteger = 16777216;
+    let mut result = old_secret;
+
+    result = ((result * 64) ^ result) % PRUNE;
+    result = ((result / 32) ^ result) % PRUNE;
+    result = ((result * 2048) ^ result) % PRUNE;
+
+    result
+}
+
+fn price(secret: UsedInteger) -> UsedInteger {
+    secret % 10
+}
+
+fn results(nums: &[UsedInteger]) -> Result<(UsedInteger, u16), Errors> {
+    let mut price_change_sequence_profit = [u16::default(); ARRAY_SIZE];
+    let mut result1 = UsedInteger::default();
+
+    const STEPS: usize = 2000;
+
+    for &num in nums {
+        let mut secret = num;
+        let mut old_price = price(secret);
+        let mut price_change_sequence = PriceChangeSequence::default();
+        let mut occurred_price_change_sequences = [false; ARRAY_SIZE];
+        for i in 0..STEPS {
+            secret = new_secret(secret);
+            let price = price(secret);
+
+            price_change_sequence.push_back(old_price - price);
+
+            let idx = price_change_sequence.to_idx();
+
+            if i >= 3 && !occurred_price_change_sequences[idx] {
+                price_change_sequence_profit[idx] += price as u16;
+
+                occurred_price_change_sequences[idx] = true;
+            }
+
+            old_price = price;
+        }
+        result1 += secret;
+    }
+
+    Ok((
+        result1,
+        price_change_sequence_profit
+            .iter()
+            .max()
+            .copied()
+            .expect("Best value expected"),
+    ))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<UsedInteger>;
+    type Answer1 = UsedInteger;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(input
+            .trim_end()
+            .split('\n')
+            .map(|s| s.parse::<UsedInteger>())
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<UsedInteger, Errors> {
+        Ok(results(input)?.0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u16, Errors> {
+        Ok(results(input)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    util::examples! {
+        Day,
+        example1: "1\n10\n100\n2024\n" => part1 = 37327623;
+        example2: "1\n2\n3\n2024\n" => part2 = 23;
+    }
+}
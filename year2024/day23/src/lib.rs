@@ -1,4 +1,6 @@
 use rustc_hash::{FxHashMap, FxHashSet};
+use util::error::Errors;
+use util::solution::Solution;
 
 fn bron_kerbosch(
     links: &FxHashMap<[u8; 2], FxHashSet<[u8; 2]>>,
@@ -37,12 +39,19 @@ fn bron_kerbosch(
     candidate
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let raw_input = std::fs::read_to_string("input")?;
-    let raw_lines = raw_input.trim_end().split('\n').collect::<Box<[_]>>();
+type Links = FxHashMap<[u8; 2], FxHashSet<[u8; 2]>>;
 
-    let links = {
-        let mut links = FxHashMap::<[u8; 2], FxHashSet<[u8; 2]>>::default();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Links;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let raw_lines = input.trim_end().split('\n').collect::<Box<[_]>>();
+
+        let mut links = Links::default();
 
         for (from, to) in raw_lines
             .iter()
@@ -56,13 +65,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             links.entry(to).or_default().insert(from);
         }
 
-        links
-    };
+        Ok(links)
+    }
 
-    let result1 = {
+    fn part1(links: &Self::Input) -> Result<usize, Errors> {
         let mut cliques_of_three = FxHashSet::default();
 
-        for (&peer0, links_to) in &links {
+        for (&peer0, links_to) in links {
             for &peer1 in links_to {
                 if peer1 <= peer0 {
                     continue;
@@ -84,7 +93,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
-        cliques_of_three
+        Ok(cliques_of_three
             .iter()
             .filter(|c| {
                 c.iter().any(|name| {
@@ -93,15 +102,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .starts_with('t')
                 })
             })
-            .count()
-    };
-
-    println!("{}", result1);
+            .count())
+    }
 
-    let result2 = {
+    fn part2(links: &Self::Input) -> Result<String, Errors> {
         let vertices = links.keys().copied().collect::<FxHashSet<_>>();
         let biggest_clique =
-            bron_kerbosch(&links, FxHashSet::default(), vertices, FxHashSet::default())
+            bron_kerbosch(links, FxHashSet::default(), vertices, FxHashSet::default())
                 .expect("Biggest clique expected");
 
         let mut biggest_clique_collected = biggest_clique
@@ -109,10 +116,52 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .map(|c| std::str::from_utf8(c).expect("Valid utf-8 expected"))
             .collect::<Box<[_]>>();
         biggest_clique_collected.sort();
-        biggest_clique_collected.join(",")
-    };
 
-    println!("{}", result2);
+        Ok(biggest_clique_collected.join(","))
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 7, part2 = "co,de,ka,ta";
+    }
 }
@@ -1,6 +1,7 @@
 use rustc_hash::FxHashMap;
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 type Bit = bool;
 
@@ -78,14 +79,25 @@ fn eval_part1(data: &mut FxHashMap<Name, Part1Wire>, name: Name) -> Bit {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let raw_input = std::fs::read_to_string("input")?;
-    let raw_lines = raw_input.trim_end().split('\n').collect::<Box<[_]>>();
-    let raw_line_blocks = raw_lines.split(|s| s.is_empty()).collect::<Box<[_]>>();
-    let raw_inputs = raw_line_blocks[0];
-    let raw_evaluations = raw_line_blocks[1];
+pub struct Circuit {
+    wire_map: FxHashMap<Name, Part1Wire>,
+    targets: Vec<Name>,
+    gate_to_wire: FxHashMap<EvalWire, Name>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Circuit;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let raw_lines = input.trim_end().split('\n').collect::<Box<[_]>>();
+        let raw_line_blocks = raw_lines.split(|s| s.is_empty()).collect::<Box<[_]>>();
+        let raw_inputs = raw_line_blocks[0];
+        let raw_evaluations = raw_line_blocks[1];
 
-    let (wire_map, targets, gate_to_wire) = {
         let mut wire_map = FxHashMap::default();
 
         for inp in raw_inputs {
@@ -121,7 +133,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             if name
                 .as_ref()
-                .chars().next()
+                .chars()
+                .next()
                 .expect("First character expected")
                 == 'z'
             {
@@ -131,12 +144,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         targets.sort();
 
-        (wire_map, targets, gate_to_wire)
-    };
+        Ok(Circuit {
+            wire_map,
+            targets,
+            gate_to_wire,
+        })
+    }
+
+    fn part1(
+        Circuit {
+            wire_map,
+            targets,
+            gate_to_wire: _,
+        }: &Self::Input,
+    ) -> Result<usize, Errors> {
+        let mut wire_map = wire_map.clone();
 
-    let result1 = {
-        let mut wire_map = wire_map;
-        targets
+        Ok(targets
             .iter()
             .rev()
             .map(|t| {
@@ -146,12 +170,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     true => 1usize,
                 }
             })
-            .fold(0, |acc, next| acc * 2 + next)
-    };
-
-    println!("{}", result1);
+            .fold(0, |acc, next| acc * 2 + next))
+    }
 
-    let result2 = {
+    fn part2(
+        Circuit {
+            wire_map: _,
+            targets,
+            gate_to_wire,
+        }: &Self::Input,
+    ) -> Result<String, Errors> {
         let max_index =
             targets.last().expect("Last target expected").as_ref()[1..3].parse::<usize>()?;
 
@@ -231,14 +259,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
 
         swaps.sort();
-        swaps
+
+        Ok(swaps
             .iter()
             .map(|s| s.as_ref())
             .collect::<Box<[_]>>()
-            .join(",")
-    };
-
-    println!("{}", result2);
+            .join(","))
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const SMALL: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    // Part 2 looks for swapped wires in an adder, which the examples are not.
+    util::examples! {
+        Day,
+        small: SMALL => part1 = 4;
+    }
 }
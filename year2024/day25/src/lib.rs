@@ -0,0 +1,129 @@
+use util::error::Errors;
+use util::solution::{NoAnswer, Solution};
+
+fn transform_iter<'a>(it: impl Iterator<Item = &'a &'a str>) -> [u8; 5] {
+    let mut result = [5u8; 5];
+
+    for (num, &line) in it.skip(1).take(5).enumerate() {
+        for (numc, c) in line.chars().enumerate() {
+            if c == '.' {
+                result[numc] = std::cmp::min(result[numc], num as u8);
+            }
+        }
+    }
+
+    result
+}
+
+fn transform_lock(lines: &[&str]) -> [u8; 5] {
+    transform_iter(lines.iter())
+}
+
+fn transform_key(lines: &[&str]) -> [u8; 5] {
+    transform_iter(lines.iter().rev())
+}
+
+fn matches(lock: [u8; 5], key: [u8; 5]) -> bool {
+    lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Box<[[u8; 5]]>, Box<[[u8; 5]]>);
+    type Answer1 = usize;
+    type Answer2 = NoAnswer;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let raw_lines = input.trim_end().split('\n').collect::<Box<[_]>>();
+        let raw_elements = raw_lines.split(|l| l.is_empty()).collect::<Box<[_]>>();
+
+        let (raw_locks, raw_keys): (Vec<_>, Vec<_>) = raw_elements.iter().copied().partition(|e| {
+            e.first()
+                .is_some_and(|first| first.chars().all(|c| c == '#'))
+        });
+
+        let locks = raw_locks
+            .iter()
+            .map(|l| transform_lock(l))
+            .collect::<Box<[_]>>();
+        let keys = raw_keys
+            .iter()
+            .map(|k| transform_key(k))
+            .collect::<Box<[_]>>();
+
+        Ok((locks, keys))
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Result<usize, Errors> {
+        let mut result1 = 0usize;
+
+        for &l in locks {
+            for &k in keys {
+                if matches(l, k) {
+                    result1 += 1;
+                }
+            }
+        }
+
+        Ok(result1)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<NoAnswer, Errors> {
+        Ok(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 3;
+    }
+}
@@ -1,8 +1,9 @@
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
-enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
@@ -17,7 +18,7 @@ impl Direction {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Turn {
+pub struct Turn {
     direction: Direction,
     steps: u32,
 }
@@ -36,18 +37,12 @@ impl FromStr for Turn {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|line| line.parse::<Turn>())
-        .collect::<Result<Vec<_>, Errors>>()?;
-
+fn results(input: &[Turn]) -> Result<(usize, usize), Errors> {
     let mut result_part1 = 0usize;
     let mut result_part2 = 0usize;
 
     let mut position = 50_i32;
-    for turn in &input {
+    for turn in input {
         let abs = turn.steps;
         let dir = turn.direction.value();
 
@@ -71,8 +66,52 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("{result_part1}");
-    println!("{result_part2}");
+    Ok((result_part1, result_part2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Turn>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Turn>())
+            .collect::<Result<Vec<_>, Errors>>()
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(results(input)?.0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(results(input)?.1)
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 3, part2 = 6;
+    }
 }
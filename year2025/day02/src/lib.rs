@@ -1,9 +1,10 @@
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
-struct IdRange {
+pub struct IdRange {
     min: i64,
     max: i64,
 }
@@ -32,7 +33,7 @@ impl From<IdRange> for RangeInclusive<i64> {
 fn is_invalid_1(id: i64) -> bool {
     let str = id.to_string();
     let len = str.len();
-    if len % 2 == 1 {
+    if !len.is_multiple_of(2) {
         return false;
     }
 
@@ -46,7 +47,7 @@ fn is_invalid_2(id: i64) -> bool {
     let len = str.len();
 
     for sublen in 1..=len / 2 {
-        if len % sublen != 0 {
+        if !len.is_multiple_of(sublen) {
             continue;
         }
         let substr = str.get(..sublen).unwrap();
@@ -66,30 +67,56 @@ fn is_invalid_2(id: i64) -> bool {
     false
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split(',')
-        .map(|line| line.parse::<IdRange>())
-        .collect::<Result<Vec<_>, Errors>>()?;
+fn sum_invalid(input: &[IdRange], is_invalid: fn(i64) -> bool) -> Result<i64, Errors> {
+    let mut result = 0_i64;
 
-    let mut result_part1 = 0_i64;
-    let mut result_part2 = 0_i64;
-
-    for id_range in input {
+    for &id_range in input {
         let id_range: RangeInclusive<i64> = id_range.into();
         for id in id_range {
-            if is_invalid_1(id) {
-                result_part1 += id;
-            }
-            if is_invalid_2(id) {
-                result_part2 += id;
+            if is_invalid(id) {
+                result += id;
             }
         }
     }
 
-    println!("{result_part1}");
-    println!("{result_part2}");
+    Ok(result)
+}
+
+pub struct Day;
 
-    Ok(())
+impl Solution for Day {
+    type Input = Vec<IdRange>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split(',')
+            .map(|line| line.parse::<IdRange>())
+            .collect::<Result<Vec<_>, Errors>>()
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64, Errors> {
+        sum_invalid(input, is_invalid_1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64, Errors> {
+        sum_invalid(input, is_invalid_2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+824824821-824824827,2121212118-2121212124
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 1227775554, part2 = 4174379265u64;
+    }
 }
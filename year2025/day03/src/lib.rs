@@ -1,8 +1,9 @@
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Clone)]
-struct Line {
+pub struct Line {
     entries: Vec<u8>,
 }
 
@@ -50,23 +51,43 @@ impl Line {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|line| line.parse::<Line>())
-        .collect::<Result<Vec<_>, Errors>>()?;
+pub struct Day;
 
-    let mut result_part1 = 0_u64;
-    let mut result_part2 = 0_u64;
+impl Solution for Day {
+    type Input = Vec<Line>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    for line in input {
-        result_part1 += line.solve(2);
-        result_part2 += line.solve(12);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Line>())
+            .collect::<Result<Vec<_>, Errors>>()
     }
 
-    println!("{result_part1}");
-    println!("{result_part2}");
+    fn part1(input: &Self::Input) -> Result<u64, Errors> {
+        Ok(input.iter().map(|line| line.solve(2)).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Errors> {
+        Ok(input.iter().map(|line| line.solve(12)).sum())
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 357, part2 = 3121910778619u64;
+    }
 }
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+use util::coord::UCoord2D;
+use util::error::Errors;
+use util::grid2d::UGrid2D;
+use util::solution::Solution;
+
+#[derive(Debug, Clone)]
+enum Slot {
+    Empty,
+    Paper,
+}
+
+impl TryFrom<char> for Slot {
+    type Error = Errors;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Slot::Empty),
+            '@' => Ok(Slot::Paper),
+            _ => Err(Errors::ParseError),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    grid: UGrid2D<Slot>,
+}
+
+impl FromStr for Field {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = UGrid2D::parse(s, |c, _| Slot::try_from(c))?;
+
+        Ok(Self { grid })
+    }
+}
+
+impl Field {
+    fn paper_neighbors(&self, coord: UCoord2D) -> usize {
+        self.grid
+            .neighbors8(coord)
+            .filter(|(_, slot)| matches!(slot, Slot::Paper))
+            .count()
+    }
+
+    fn part1(&self) -> Result<usize, Errors> {
+        Ok(self
+            .grid
+            .iter()
+            .filter(|&(coord, slot)| matches!(slot, Slot::Paper) && self.paper_neighbors(coord) < 4)
+            .count())
+    }
+
+    fn part2_mut(&mut self) -> Result<usize, Errors> {
+        let mut result = 0_usize;
+
+        let dim = self.grid.dim();
+
+        loop {
+            let mut changed = false;
+
+            for y in 0..dim.y() {
+                for x in 0..dim.x() {
+                    let coord = UCoord2D::new(x, y);
+                    let slot = self.grid.get(coord)?;
+
+                    if let Slot::Empty = slot {
+                        continue;
+                    }
+
+                    let count = self.paper_neighbors(coord);
+
+                    if count < 4 {
+                        result += 1;
+                        *self.grid.get_mut(coord)? = Slot::Empty;
+                        changed = true;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input.trim_end().parse::<Field>()
+    }
+
+    fn part1(field: &Self::Input) -> Result<usize, Errors> {
+        field.part1()
+    }
+
+    fn part2(field: &Self::Input) -> Result<usize, Errors> {
+        field.clone().part2_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const GRID: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    util::examples! {
+        Day,
+        grid: GRID => part1 = 13, part2 = 43;
+    }
+}
@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
 struct IngredientRange {
@@ -12,7 +13,7 @@ struct IngredientRange {
 impl FromStr for IngredientRange {
     type Err = Errors;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (min, max) = s.split_once('-').ok_or_else(|| Errors::ParseError)?;
+        let (min, max) = s.split_once('-').ok_or(Errors::ParseError)?;
         Ok(Self {
             min: min.parse()?,
             max: max.parse()?,
@@ -20,9 +21,9 @@ impl FromStr for IngredientRange {
     }
 }
 
-impl Into<RangeInclusive<usize>> for IngredientRange {
-    fn into(self) -> RangeInclusive<usize> {
-        self.min..=self.max
+impl From<IngredientRange> for RangeInclusive<usize> {
+    fn from(range: IngredientRange) -> Self {
+        range.min..=range.max
     }
 }
 
@@ -38,7 +39,7 @@ impl IngredientRange {
 }
 
 #[derive(Debug, Clone)]
-struct IngredientRanges {
+pub struct IngredientRanges {
     ranges: Vec<IngredientRange>,
 }
 
@@ -93,7 +94,7 @@ impl FromStr for IngredientRanges {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Ingredient {
+pub struct Ingredient {
     id: usize,
 }
 
@@ -110,31 +111,61 @@ impl Borrow<usize> for Ingredient {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?;
-    let input_split = input.trim_end().split("\n\n").collect::<Vec<_>>();
-    let ingredient_ranges = input_split[0].parse::<IngredientRanges>()?.merge_ranges();
-    let ingredients = input_split[1]
-        .lines()
-        .map(|line| line.parse::<Ingredient>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub struct Day;
 
-    let result_part1 = {
+impl Solution for Day {
+    type Input = (IngredientRanges, Vec<Ingredient>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let input_split = input.trim_end().split("\n\n").collect::<Vec<_>>();
+        let ingredient_ranges = input_split[0].parse::<IngredientRanges>()?.merge_ranges();
+        let ingredients = input_split[1]
+            .lines()
+            .map(|line| line.parse::<Ingredient>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((ingredient_ranges, ingredients))
+    }
+
+    fn part1((ingredient_ranges, ingredients): &Self::Input) -> Result<usize, Errors> {
         let mut result_part1 = 0_usize;
 
-        for ingredient in ingredients {
+        for &ingredient in ingredients {
             if ingredient_ranges.contains(ingredient) {
                 result_part1 += 1;
             }
         }
 
-        result_part1
-    };
-
-    let result_part2 = ingredient_ranges.len();
+        Ok(result_part1)
+    }
 
-    println!("{result_part1}");
-    println!("{result_part2}");
+    fn part2((ingredient_ranges, _): &Self::Input) -> Result<usize, Errors> {
+        Ok(ingredient_ranges.len())
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 3, part2 = 14;
+    }
 }
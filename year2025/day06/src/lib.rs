@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use util::error::Errors;
 use util::grid2d::{UCoord2D, UGrid2D};
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
 enum Operation {
@@ -58,12 +59,9 @@ impl FromStr for Field1 {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let dim = UCoord2D {
-            x: numbers[0].len(),
-            y: numbers.len(),
-        };
+        let dim = UCoord2D::new(numbers[0].len(), numbers.len());
 
-        let ugrid2d = UGrid2D::generate(dim, |coord| Ok(numbers[coord.y][coord.x]))?;
+        let ugrid2d = UGrid2D::generate(dim, |coord| Ok(numbers[coord.y()][coord.x()]))?;
 
         let opers = lines
             .into_iter()
@@ -73,7 +71,7 @@ impl FromStr for Field1 {
             .map(|s| s.parse())
             .collect::<Result<Vec<Operation>, _>>()?;
 
-        assert_eq!(dim.x, opers.len());
+        assert_eq!(dim.x(), opers.len());
 
         Ok(Self { ugrid2d, opers })
     }
@@ -83,11 +81,11 @@ impl Field1 {
     fn result(&self) -> Result<u64, Errors> {
         let mut result = 0;
 
-        let y_size = self.ugrid2d.dim().y;
+        let y_size = self.ugrid2d.dim().y();
         for (x, op) in self.opers.iter().enumerate() {
             let mut col_result = op.neutral_element();
             for i_y in 0..y_size {
-                let coord = UCoord2D { x, y: i_y };
+                let coord = UCoord2D::new(x, i_y);
 
                 col_result = op.execute(col_result, *self.ugrid2d.get(coord)?);
             }
@@ -147,8 +145,8 @@ impl FromStr for Field2 {
 
             let mut found_value = None;
 
-            for i_line in 0..num_lines - 1 {
-                let potential_value = lines[i_line].get(i_sgn_col..=i_sgn_col);
+            for line in &lines[..num_lines - 1] {
+                let potential_value = line.get(i_sgn_col..=i_sgn_col);
                 if let Some(potential_value) = potential_value {
                     is_beyond_line_end = false;
                     let potential_value = potential_value.parse::<u64>().ok();
@@ -182,19 +180,41 @@ impl Field2 {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string("input")?;
+pub struct Day;
 
-    let field1 = input.parse::<Field1>()?;
+// Both parts read the worksheet differently, so parsing is left to each part.
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let result_part1 = field1.result()?;
-
-    let field2 = input.parse::<Field2>()?;
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        Ok(input.to_owned())
+    }
 
-    let result_part2 = field2.result();
+    fn part1(input: &Self::Input) -> Result<u64, Errors> {
+        input.parse::<Field1>()?.result()
+    }
 
-    println!("{result_part1}");
-    println!("{result_part2}");
+    fn part2(input: &Self::Input) -> Result<u64, Errors> {
+        Ok(input.parse::<Field2>()?.result())
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    // The trailing spaces are significant for part 2.
+    const EXAMPLE: &str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 4277556, part2 = 3263827;
+    }
 }
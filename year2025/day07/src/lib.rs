@@ -0,0 +1,143 @@
+use std::str::FromStr;
+use util::coord::ICoord2D;
+use util::error::Errors;
+use util::grid2d::{UCoord2D, UGrid2D};
+use util::search::dijkstra_all_paths;
+use util::solution::Solution;
+
+#[derive(Debug, Clone, Copy)]
+enum Element {
+    Splitter,
+    Empty,
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    ugrid2d: UGrid2D<Element>,
+    start: UCoord2D,
+}
+
+impl FromStr for Field {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ugrid2d, markers) = UGrid2D::parse_with_markers(s, &['S'], |c, _| match c {
+            '.' | 'S' => Ok(Element::Empty),
+            '^' => Ok(Element::Splitter),
+            _ => Err(Errors::ParseError),
+        })?;
+
+        let start = *markers.get(&'S').ok_or(Errors::ParseError)?;
+
+        Ok(Field { ugrid2d, start })
+    }
+}
+
+impl Field {
+    // Where a beam entering below `beam_start` stops: on a splitter, or just below the grid.
+    fn simulate_beam(&self, beam_start: UCoord2D) -> UCoord2D {
+        let mut beam_pos = beam_start;
+
+        loop {
+            beam_pos += UCoord2D::new(0, 1);
+
+            match self.ugrid2d.get(beam_pos) {
+                Ok(Element::Empty) => continue,
+                Ok(Element::Splitter) | Err(_) => return beam_pos,
+            }
+        }
+    }
+
+    fn is_splitter(&self, pos: UCoord2D) -> bool {
+        matches!(self.ugrid2d.get(pos), Ok(Element::Splitter))
+    }
+
+    // The stops reached from the start or from a splitter, with the rows travelled as cost.
+    fn next_stops(&self, pos: UCoord2D) -> Vec<(UCoord2D, usize)> {
+        let beam_starts = if pos == self.start {
+            vec![pos]
+        } else if self.is_splitter(pos) {
+            [ICoord2D::new(-1, 0), ICoord2D::new(1, 0)]
+                .into_iter()
+                .filter_map(|offset| self.ugrid2d.coord_to_grid(pos + offset))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        beam_starts
+            .into_iter()
+            .map(|beam_start| {
+                let stop = self.simulate_beam(beam_start);
+                (stop, stop.y() - pos.y())
+            })
+            .collect()
+    }
+
+    fn result(&self) -> (usize, usize) {
+        // Beams only go down, so every way to a stop takes the same number of rows, and every
+        // timeline is a cheapest path to one of the stops below the grid.
+        let bottom = self.ugrid2d.dim().y();
+        let search = dijkstra_all_paths(
+            self.start,
+            |&pos| self.next_stops(pos),
+            |pos| pos.y() == bottom,
+        );
+
+        let splits = search
+            .distances()
+            .keys()
+            .filter(|&&pos| self.is_splitter(pos))
+            .count();
+
+        (splits, search.count_optimal_paths())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input.trim_end().parse::<Field>()
+    }
+
+    fn part1(field: &Self::Input) -> Result<usize, Errors> {
+        Ok(field.result().0)
+    }
+
+    fn part2(field: &Self::Input) -> Result<usize, Errors> {
+        Ok(field.result().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 21, part2 = 40;
+    }
+}
@@ -0,0 +1,173 @@
+use std::str::FromStr;
+use util::coord::ICoord3D;
+use util::dsu::DisjointSet;
+use util::error::Errors;
+use util::solution::Solution;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Coord {
+    pos: ICoord3D,
+}
+
+impl FromStr for Coord {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { pos: s.parse()? })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct CoordPair {
+    from: Coord,
+    to: Coord,
+}
+
+impl CoordPair {
+    fn dist(&self) -> usize {
+        self.from.pos.euclidean_sq(self.to.pos)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CoordPairDist {
+    dist: usize,
+    pair: CoordPair,
+}
+
+// Connects the closest pairs in order. Part 1 is taken after the first `connections` pairs,
+// part 2 once all boxes form a single circuit.
+fn solutions(
+    coords: &[Coord],
+    coord_pair_dists: &[CoordPairDist],
+    connections: usize,
+) -> (Option<usize>, isize) {
+    let mut circuits = coords.iter().copied().collect::<DisjointSet<_>>();
+
+    let mut solution_part1 = None;
+
+    for (
+        idx,
+        CoordPairDist {
+            dist: _,
+            pair: CoordPair { from, to },
+        },
+    ) in coord_pair_dists.iter().enumerate()
+    {
+        circuits.union(from, to);
+
+        if idx + 1 == connections {
+            solution_part1 = Some(circuits.largest(3).iter().product());
+        }
+
+        if circuits.component_count() == 1 {
+            return (solution_part1, from.pos.x() * to.pos.x());
+        }
+    }
+
+    panic!("no coord should remain unconnected");
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Coord>, Vec<CoordPairDist>);
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        let coords = input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Coord>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let coord_pair_dists = {
+            let coord_pairs = {
+                let mut coord_pairs = Vec::new();
+
+                for (i0, coord0) in coords.iter().enumerate() {
+                    for (i1, coord1) in coords.iter().enumerate() {
+                        if i0 < i1 {
+                            coord_pairs.push(CoordPair {
+                                from: *coord0,
+                                to: *coord1,
+                            });
+                        }
+                    }
+                }
+
+                coord_pairs
+            };
+
+            let mut coord_pair_dists = coord_pairs
+                .into_iter()
+                .map(|p| CoordPairDist {
+                    dist: p.dist(),
+                    pair: p,
+                })
+                .collect::<Vec<_>>();
+
+            coord_pair_dists.sort_unstable_by_key(|p| p.dist);
+
+            coord_pair_dists
+        };
+
+        Ok((coords, coord_pair_dists))
+    }
+
+    fn part1((coords, coord_pair_dists): &Self::Input) -> Result<usize, Errors> {
+        solutions(coords, coord_pair_dists, 1000)
+            .0
+            .ok_or(Errors::UncategorizedError(
+                "All boxes connected before 1000 connections".into(),
+            ))
+    }
+
+    fn part2((coords, coord_pair_dists): &Self::Input) -> Result<isize, Errors> {
+        Ok(solutions(coords, coord_pair_dists, 1000).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    use super::solutions;
+    use util::solution::Solution;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    // Part 1 connects 1000 pairs, more than the example has, so it is checked with 10 below.
+    util::examples! {
+        Day,
+        example: EXAMPLE => part2 = 25272;
+    }
+
+    #[test]
+    fn example_ten_connections() {
+        let (coords, coord_pair_dists) = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(solutions(&coords, &coord_pair_dists, 10).0, Some(40));
+    }
+}
@@ -1,6 +1,7 @@
 use std::str::FromStr;
-use util::coord2d::ICoord2D;
+use util::coord::ICoord2D;
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Debug, Copy, Clone)]
 struct Tile {
@@ -10,20 +11,8 @@ struct Tile {
 impl FromStr for Tile {
     type Err = Errors;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let elems = s
-            .split(',')
-            .map(|e| e.parse::<isize>())
-            .collect::<Result<Vec<_>, _>>()?;
-
-        if elems.len() != 2 {
-            return Err(Errors::ParseError);
-        }
-
         Ok(Self {
-            icoord2d: ICoord2D {
-                x: elems[0],
-                y: elems[1],
-            },
+            icoord2d: s.parse()?,
         })
     }
 }
@@ -53,15 +42,15 @@ impl Line {
             (Orientation::Degenerate, _) => other.contains_point(self.start),
             (_, Orientation::Degenerate) => self.contains_point(other.start),
             (Orientation::Horizontal, Orientation::Horizontal) => {
-                if self.start.y != other.start.y {
+                if self.start.y() != other.start.y() {
                     return false;
                 }
 
-                let self_min_x = self.start.x.min(self.end.x);
-                let self_max_x = self.start.x.max(self.end.x);
+                let self_min_x = self.start.x().min(self.end.x());
+                let self_max_x = self.start.x().max(self.end.x());
 
-                let other_min_x = other.start.x.min(other.end.x);
-                let other_max_x = other.start.x.max(other.end.x);
+                let other_min_x = other.start.x().min(other.end.x());
+                let other_max_x = other.start.x().max(other.end.x());
 
                 let max_of_min_x = self_min_x.max(other_min_x);
                 let min_of_max_x = self_max_x.min(other_max_x);
@@ -69,15 +58,15 @@ impl Line {
                 max_of_min_x <= min_of_max_x
             }
             (Orientation::Vertical, Orientation::Vertical) => {
-                if self.start.x != other.start.x {
+                if self.start.x() != other.start.x() {
                     return false;
                 }
 
-                let self_min_y = self.start.y.min(self.end.y);
-                let self_max_y = self.start.y.max(self.end.y);
+                let self_min_y = self.start.y().min(self.end.y());
+                let self_max_y = self.start.y().max(self.end.y());
 
-                let other_min_y = other.start.y.min(other.end.y);
-                let other_max_y = other.start.y.max(other.end.y);
+                let other_min_y = other.start.y().min(other.end.y());
+                let other_max_y = other.start.y().max(other.end.y());
 
                 let max_of_min_y = self_min_y.max(other_min_y);
                 let min_of_max_y = self_max_y.min(other_max_y);
@@ -85,33 +74,35 @@ impl Line {
                 max_of_min_y <= min_of_max_y
             }
             (Orientation::Horizontal, Orientation::Vertical) => {
-                let x_min = self.start.x.min(self.end.x);
-                let x_max = self.start.x.max(self.end.x);
-                let y_min = other.start.y.min(other.end.y);
-                let y_max = other.start.y.max(other.end.y);
+                let x_min = self.start.x().min(self.end.x());
+                let x_max = self.start.x().max(self.end.x());
+                let y_min = other.start.y().min(other.end.y());
+                let y_max = other.start.y().max(other.end.y());
 
-                (x_min..=x_max).contains(&other.start.x) && (y_min..=y_max).contains(&self.start.y)
+                (x_min..=x_max).contains(&other.start.x())
+                    && (y_min..=y_max).contains(&self.start.y())
             }
             (Orientation::Vertical, Orientation::Horizontal) => {
-                let x_min = other.start.x.min(other.end.x);
-                let x_max = other.start.x.max(other.end.x);
-                let y_min = self.start.y.min(self.end.y);
-                let y_max = self.start.y.max(self.end.y);
+                let x_min = other.start.x().min(other.end.x());
+                let x_max = other.start.x().max(other.end.x());
+                let y_min = self.start.y().min(self.end.y());
+                let y_max = self.start.y().max(self.end.y());
 
-                (x_min..=x_max).contains(&self.start.x) && (y_min..=y_max).contains(&other.start.y)
+                (x_min..=x_max).contains(&self.start.x())
+                    && (y_min..=y_max).contains(&other.start.y())
             }
         }
     }
 
     fn orientation(&self) -> Orientation {
-        if self.start.x == self.end.x {
-            if self.start.y == self.end.y {
+        if self.start.x() == self.end.x() {
+            if self.start.y() == self.end.y() {
                 Orientation::Degenerate
             } else {
                 Orientation::Vertical
             }
         } else {
-            debug_assert_eq!(self.start.y, self.end.y);
+            debug_assert_eq!(self.start.y(), self.end.y());
             Orientation::Horizontal
         }
     }
@@ -129,14 +120,14 @@ impl Line {
     fn contains_point(&self, point: ICoord2D) -> bool {
         match self.orientation() {
             Orientation::Horizontal => {
-                let x_min = self.start.x.min(self.end.x);
-                let x_max = self.start.x.max(self.end.x);
-                point.y == self.start.y && (x_min..=x_max).contains(&point.x)
+                let x_min = self.start.x().min(self.end.x());
+                let x_max = self.start.x().max(self.end.x());
+                point.y() == self.start.y() && (x_min..=x_max).contains(&point.x())
             }
             Orientation::Vertical => {
-                let y_min = self.start.y.min(self.end.y);
-                let y_max = self.start.y.max(self.end.y);
-                point.x == self.start.x && (y_min..=y_max).contains(&point.y)
+                let y_min = self.start.y().min(self.end.y());
+                let y_max = self.start.y().max(self.end.y());
+                point.x() == self.start.x() && (y_min..=y_max).contains(&point.y())
             }
             Orientation::Degenerate => self.start == point,
         }
@@ -147,21 +138,18 @@ impl Rectangle {
     fn inner(mut self) -> Self {
         let diff = self.to.icoord2d - self.from.icoord2d;
 
-        let x_sgn = diff.x.signum();
-        let y_sgn = diff.y.signum();
+        let step = diff.signum();
 
-        self.from.icoord2d.x += x_sgn;
-        self.from.icoord2d.y += y_sgn;
-        self.to.icoord2d.x -= x_sgn;
-        self.to.icoord2d.y -= y_sgn;
+        self.from.icoord2d += step;
+        self.to.icoord2d -= step;
 
         self
     }
 
     fn area(&self) -> usize {
         let diff = self.from.icoord2d - self.to.icoord2d;
-        let x_size = diff.x.abs() + 1;
-        let y_size = diff.y.abs() + 1;
+        let x_size = diff.x().abs() + 1;
+        let y_size = diff.y().abs() + 1;
 
         (x_size * y_size) as usize
     }
@@ -176,16 +164,10 @@ impl Rectangle {
     fn extra_corners(&self) -> [Tile; 2] {
         [
             Tile {
-                icoord2d: ICoord2D {
-                    x: self.from.icoord2d.x,
-                    y: self.to.icoord2d.y,
-                },
+                icoord2d: ICoord2D::new(self.from.icoord2d.x(), self.to.icoord2d.y()),
             },
             Tile {
-                icoord2d: ICoord2D {
-                    x: self.to.icoord2d.x,
-                    y: self.from.icoord2d.y,
-                },
+                icoord2d: ICoord2D::new(self.to.icoord2d.x(), self.from.icoord2d.y()),
             },
         ]
     }
@@ -194,17 +176,11 @@ impl Rectangle {
         let corners = [
             self.from,
             Tile {
-                icoord2d: ICoord2D {
-                    x: self.from.icoord2d.x,
-                    y: self.to.icoord2d.y,
-                },
+                icoord2d: ICoord2D::new(self.from.icoord2d.x(), self.to.icoord2d.y()),
             },
             self.to,
             Tile {
-                icoord2d: ICoord2D {
-                    x: self.to.icoord2d.x,
-                    y: self.from.icoord2d.y,
-                },
+                icoord2d: ICoord2D::new(self.to.icoord2d.x(), self.from.icoord2d.y()),
             },
         ];
 
@@ -230,7 +206,7 @@ impl Rectangle {
 }
 
 #[derive(Debug, Clone)]
-struct Polygon {
+pub struct Polygon {
     points: Vec<Tile>,
 }
 
@@ -269,11 +245,7 @@ impl Polygon {
 
         let long_lines = tiles.map(|t| Line {
             start: t.icoord2d,
-            end: t.icoord2d
-                + ICoord2D {
-                    x: huge_integer,
-                    y: 0,
-                },
+            end: t.icoord2d + ICoord2D::new(huge_integer, 0),
         });
 
         for line in self.lines() {
@@ -346,15 +318,47 @@ impl Polygon {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let polygon = std::fs::read_to_string("input")?
-        .trim_end()
-        .parse::<Polygon>()?;
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Polygon;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let (part1, part2) = polygon.largest_rectangle_largest_rectangle_inside_polygon();
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input.trim_end().parse::<Polygon>()
+    }
+
+    fn part1(polygon: &Self::Input) -> Result<usize, Errors> {
+        Ok(polygon
+            .largest_rectangle_largest_rectangle_inside_polygon()
+            .0)
+    }
 
-    println!("{part1}");
-    println!("{part2}");
+    fn part2(polygon: &Self::Input) -> Result<usize, Errors> {
+        Ok(polygon
+            .largest_rectangle_largest_rectangle_inside_polygon()
+            .1)
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 50, part2 = 24;
+    }
 }
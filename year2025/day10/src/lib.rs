@@ -1,5 +1,6 @@
 use std::str::FromStr;
 use util::error::Errors;
+use util::solution::Solution;
 use z3::{
     Optimize,
     ast::{Bool, Int},
@@ -81,7 +82,7 @@ impl Vals {
 }
 
 #[derive(Debug, Clone)]
-struct Machine {
+pub struct Machine {
     lights: Vals,
     buttons: Vec<Vals>,
     joltages: Vals,
@@ -98,7 +99,7 @@ fn bool_to_int(b: &Bool) -> Int {
 }
 
 fn sum_bool(slice: &[&Bool]) -> Int {
-    let ints: Vec<_> = slice.into_iter().map(|b| bool_to_int(b)).collect();
+    let ints: Vec<_> = slice.iter().map(|b| bool_to_int(b)).collect();
     Int::add(&ints)
 }
 
@@ -228,28 +229,52 @@ impl FromStr for Machine {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let machines = std::fs::read_to_string("input")?
-        .trim_end()
-        .split('\n')
-        .map(|line| line.parse::<Machine>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub struct Day;
 
-    let part1 = machines
-        .iter()
-        .map(|machine| machine.part1())
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .sum::<usize>();
-    let part2 = machines
-        .iter()
-        .map(|machine| machine.part2())
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .sum::<usize>();
+impl Solution for Day {
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("{}", part1);
-    println!("{}", part2);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input
+            .trim_end()
+            .split('\n')
+            .map(|line| line.parse::<Machine>())
+            .collect::<Result<Vec<_>, _>>()
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(input
+            .iter()
+            .map(|machine| machine.part1())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .sum::<usize>())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Errors> {
+        Ok(input
+            .iter()
+            .map(|machine| machine.part2())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    util::examples! {
+        Day,
+        example: EXAMPLE => part1 = 7, part2 = 33;
+    }
 }
@@ -1,6 +1,7 @@
 use ahash::{HashMap, HashMapExt};
 use std::{fmt::Display, str::FromStr};
 use util::error::Errors;
+use util::solution::Solution;
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 struct Node {
@@ -42,7 +43,7 @@ impl FromStr for Node {
 }
 
 #[derive(Debug, Clone)]
-struct Graph {
+pub struct Graph {
     edges: HashMap<Node, Vec<Node>>,
 }
 
@@ -85,7 +86,7 @@ impl Graph {
             return 1;
         }
 
-        if let Some(&result) = memo.get(&from) {
+        if let Some(&result) = memo.get(from) {
             return result;
         }
 
@@ -152,16 +153,61 @@ impl Graph {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let graph = std::fs::read_to_string("input")?
-        .trim_end()
-        .parse::<Graph>()?;
+pub struct Day;
 
-    let part1 = graph.part1();
-    let part2 = graph.part2();
+impl Solution for Day {
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("{}", part1);
-    println!("{}", part2);
+    fn parse(input: &str) -> Result<Self::Input, Errors> {
+        input.trim_end().parse::<Graph>()
+    }
+
+    fn part1(graph: &Self::Input) -> Result<usize, Errors> {
+        Ok(graph.part1())
+    }
 
-    Ok(())
+    fn part2(graph: &Self::Input) -> Result<usize, Errors> {
+        Ok(graph.part2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+
+    const EXAMPLE1: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+    const EXAMPLE2: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    util::examples! {
+        Day,
+        example1: EXAMPLE1 => part1 = 5;
+        example2: EXAMPLE2 => part2 = 2;
+    }
 }